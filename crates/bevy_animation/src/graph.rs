@@ -7,7 +7,9 @@ use core::{
 use std::io::{self, Write};
 
 use bevy_asset::{
-    io::Reader, Asset, AssetEvent, AssetId, AssetLoader, AssetPath, Assets, Handle, LoadContext,
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    Asset, AssetEvent, AssetId, AssetLoader, AssetPath, Assets, AsyncWriteExt, Handle, LoadContext,
 };
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
@@ -239,6 +241,14 @@ pub enum AnimationNodeType {
 #[derive(Default)]
 pub struct AnimationGraphAssetLoader;
 
+/// An [`AssetSaver`] that saves [`AnimationGraph`]s in RON format, so that they
+/// can be loaded back with the [`AnimationGraphAssetLoader`].
+///
+/// Animation clips are saved by asset path when they have one, and by asset ID
+/// otherwise. See [`SerializedAnimationClip`] for details.
+#[derive(Default)]
+pub struct AnimationGraphAssetSaver;
+
 /// Various errors that can occur when serializing or deserializing animation
 /// graphs to and from RON, respectively.
 #[derive(Error, Debug)]
@@ -787,6 +797,28 @@ impl AssetLoader for AnimationGraphAssetLoader {
     }
 }
 
+impl AssetSaver for AnimationGraphAssetSaver {
+    type Asset = AnimationGraph;
+
+    type Settings = ();
+
+    type OutputLoader = AnimationGraphAssetLoader;
+
+    type Error = AnimationGraphLoadError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let mut bytes = Vec::new();
+        asset.save(&mut bytes)?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
 impl From<AnimationGraph> for SerializedAnimationGraph {
     fn from(animation_graph: AnimationGraph) -> Self {
         // If any of the animation clips have paths, then serialize them as
@@ -926,3 +958,47 @@ impl ThreadedAnimationGraph {
         self.threaded_graph.push(node_index);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AnimationGraph, AnimationGraphAssetSaver, SerializedAnimationClip,
+        SerializedAnimationGraph, SerializedAnimationNodeType,
+    };
+    use crate::AnimationClip;
+    use bevy_asset::{
+        saver::{AssetSaver, SavedAsset},
+        weak_handle, Handle,
+    };
+
+    #[test]
+    fn animation_graph_saver_round_trip() {
+        let clip: Handle<AnimationClip> = weak_handle!("2c5c3f1d-57d4-4b2c-9b0f-5e2e0a2f77c1");
+        let mut graph = AnimationGraph::new();
+        let blend = graph.add_blend(0.5, graph.root);
+        let clip_node = graph.add_clip(clip.clone(), 0.25, blend);
+
+        let mut bytes = Vec::new();
+        futures_lite::future::block_on(AnimationGraphAssetSaver.save(
+            &mut bytes,
+            SavedAsset::from_asset(&graph),
+            &(),
+        ))
+        .unwrap();
+
+        let saved: SerializedAnimationGraph = ron::de::from_bytes(&bytes).unwrap();
+        assert_eq!(saved.root, graph.root);
+        assert_eq!(saved.graph.node_count(), graph.graph.node_count());
+        assert_eq!(saved.graph.edge_count(), graph.graph.edge_count());
+        assert_eq!(saved.graph[blend].weight, 0.5);
+        assert!(matches!(
+            saved.graph[blend].node_type,
+            SerializedAnimationNodeType::Blend
+        ));
+        assert_eq!(saved.graph[clip_node].weight, 0.25);
+        assert!(matches!(
+            saved.graph[clip_node].node_type,
+            SerializedAnimationNodeType::Clip(SerializedAnimationClip::AssetId(id)) if id == clip.id()
+        ));
+    }
+}
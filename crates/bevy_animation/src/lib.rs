@@ -37,7 +37,7 @@ use bevy_ecs::{prelude::*, world::EntityMutExcept};
 use bevy_math::FloatOrd;
 use bevy_platform_support::{collections::HashMap, hash::NoOpHash};
 use bevy_reflect::{prelude::ReflectDefault, Reflect, TypePath};
-use bevy_time::Time;
+use bevy_time::DomainTime;
 use bevy_transform::TransformSystem;
 use bevy_utils::{PreHashMap, PreHashMapExt, TypeIdMap};
 use petgraph::graph::NodeIndex;
@@ -978,18 +978,19 @@ fn trigger_untargeted_animation_events(
 
 /// A system that advances the time for all playing animations.
 pub fn advance_animations(
-    time: Res<Time>,
+    time: DomainTime,
     animation_clips: Res<Assets<AnimationClip>>,
     animation_graphs: Res<Assets<AnimationGraph>>,
-    mut players: Query<(&mut AnimationPlayer, &AnimationGraphHandle)>,
+    mut players: Query<(Entity, &mut AnimationPlayer, &AnimationGraphHandle)>,
 ) {
-    let delta_seconds = time.delta_secs();
     players
         .par_iter_mut()
-        .for_each(|(mut player, graph_handle)| {
+        .for_each(|(entity, mut player, graph_handle)| {
             let Some(animation_graph) = animation_graphs.get(graph_handle) else {
                 return;
             };
+            // Players inside of a `TimeDomain` are advanced by its scaled time.
+            let delta_seconds = time.delta_secs(entity);
 
             // Tick animations, and schedule them.
 
@@ -1,6 +1,6 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, PluginGraph, Plugins, PluginsState,
+    SubApp, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -142,6 +142,8 @@ impl App {
             sub_apps: SubApps {
                 main: SubApp::new(),
                 sub_apps: HashMap::default(),
+                #[cfg(feature = "std")]
+                threaded_sub_apps: HashMap::default(),
             },
             runner: Box::new(run_once),
         }
@@ -174,7 +176,8 @@ impl App {
     ///
     /// # Panics
     ///
-    /// Panics if not all plugins have been built.
+    /// Panics if not all plugins have been built, or if the [dependencies](Plugin::dependencies)
+    /// of a plugin are not satisfied.
     pub fn run(&mut self) -> AppExit {
         #[cfg(feature = "trace")]
         let _bevy_app_run_span = info_span!("bevy_app").entered();
@@ -182,11 +185,27 @@ impl App {
             panic!("App::run() was called while a plugin was building.");
         }
 
+        self.assert_plugin_dependencies();
+
         let runner = core::mem::replace(&mut self.runner, Box::new(run_once));
         let app = core::mem::replace(self, App::empty());
         (runner)(app)
     }
 
+    /// Panics with the [plugin graph](Self::plugin_graph) if the dependencies of a plugin are not
+    /// satisfied.
+    pub(crate) fn assert_plugin_dependencies(&self) {
+        let plugin_graph = self.plugin_graph();
+        let errors = plugin_graph.errors();
+        if !errors.is_empty() {
+            let errors: Vec<_> = errors.iter().map(ToString::to_string).collect();
+            panic!(
+                "The app has unsatisfied plugin dependencies:\n{}\n\nPlugin graph:\n{plugin_graph}",
+                errors.join("\n")
+            );
+        }
+    }
+
     /// Sets the function that will be called when the app is run.
     ///
     /// The runner function `f` is called only once by [`App::run`]. If the
@@ -495,6 +514,7 @@ impl App {
         }
 
         self.main_mut().plugin_registry[index] = plugin;
+        self.main_mut().plugin_build_order.push(index);
         Ok(self)
     }
 
@@ -532,6 +552,25 @@ impl App {
         self.main().get_added_plugins::<T>()
     }
 
+    /// Returns the [`PluginGraph`] of the plugins added to the main [`SubApp`], which can be
+    /// used to check or dump their [dependencies](Plugin::dependencies).
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # let mut app = App::new();
+    /// let plugin_graph = app.plugin_graph();
+    /// assert!(plugin_graph.errors().is_empty());
+    /// println!("{plugin_graph}");
+    /// ```
+    pub fn plugin_graph(&self) -> PluginGraph {
+        let main = self.main();
+        PluginGraph::new(
+            main.plugin_build_order
+                .iter()
+                .map(|&index| &*main.plugin_registry[index]),
+        )
+    }
+
     /// Installs a [`Plugin`] collection.
     ///
     /// Bevy prioritizes modularity as a core principle. **All** engine features are implemented
@@ -1436,6 +1475,7 @@ impl Termination for AppExit {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{format, string::ToString};
     use core::{iter, marker::PhantomData};
     use std::sync::Mutex;
 
@@ -1452,7 +1492,7 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Plugin, PluginDependencies, PluginDependencyError, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1497,6 +1537,50 @@ mod tests {
         App::new().add_plugins((PluginA, PluginA));
     }
 
+    struct DependentPlugin;
+    impl Plugin for DependentPlugin {
+        fn build(&self, _app: &mut App) {}
+
+        fn dependencies(&self, dependencies: &mut PluginDependencies) {
+            dependencies.require::<PluginA>().optional::<PluginB>();
+        }
+    }
+
+    #[test]
+    fn plugin_dependencies() {
+        let mut app = App::new();
+        app.add_plugins((PluginA, DependentPlugin));
+        assert!(app.plugin_graph().errors().is_empty());
+
+        let mut app = App::new();
+        app.add_plugins((DependentPlugin, PluginB));
+        let graph = app.plugin_graph();
+        let name = core::any::type_name::<DependentPlugin>();
+        assert_eq!(
+            graph.errors(),
+            [
+                PluginDependencyError::Missing {
+                    plugin: name.into(),
+                    dependency: core::any::type_name::<PluginA>(),
+                },
+                PluginDependencyError::Order {
+                    plugin: name.into(),
+                    dependency: core::any::type_name::<PluginB>(),
+                },
+            ]
+        );
+        assert!(graph.to_string().contains(&format!(
+            "{name}\n    requires {} (not added)",
+            core::any::type_name::<PluginA>()
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "unsatisfied plugin dependencies")]
+    fn cant_run_with_missing_plugin_dependency() {
+        App::new().add_plugins(DependentPlugin).run();
+    }
+
     #[test]
     fn can_add_twice_the_same_plugin_with_different_type_param() {
         App::new().add_plugins((PluginC(0), PluginC(true)));
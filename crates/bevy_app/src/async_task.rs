@@ -0,0 +1,377 @@
+use crate::{App, Plugin, PreUpdate};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    event::{Event, Events},
+    query::{QueryData, QueryFilter},
+    resource::Resource,
+    system::Query,
+    world::World,
+};
+use bevy_platform_support::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use bevy_tasks::{AsyncComputeTaskPool, Task, TaskPool};
+use core::{
+    future::{poll_fn, Future},
+    mem,
+    task::{Poll, Waker},
+};
+use log::warn;
+
+/// Adds [`AsyncTasks`], which are updated by [`run_async_tasks`] in [`PreUpdate`].
+#[derive(Default)]
+pub struct AsyncTaskPlugin;
+
+impl Plugin for AsyncTaskPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AsyncTasks>()
+            .add_systems(PreUpdate, run_async_tasks);
+    }
+}
+
+type WorldRequest = Box<dyn FnOnce(&mut World) + Send>;
+
+/// Async tasks running on the [`AsyncComputeTaskPool`] with access to the [`World`] through an
+/// [`AsyncWorld`].
+///
+/// Tasks run in parallel with the schedule, and only access the world at the sync points where
+/// [`run_async_tasks`] runs, which is once per frame in [`PreUpdate`] when using
+/// [`AsyncTaskPlugin`]. This makes them suited to long sequences spanning many frames, such as
+/// cutscenes or dialogues, which would otherwise be written as state machines.
+///
+/// ```
+/// # use bevy_app::{AsyncTasks, AsyncWorld};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Door {
+///     open: bool,
+/// }
+///
+/// #[derive(Event, Clone)]
+/// struct LeverPulled;
+///
+/// fn start_cutscene(mut tasks: ResMut<AsyncTasks>) {
+///     tasks.spawn(|world: AsyncWorld| async move {
+///         world.wait_for_event::<LeverPulled>().await;
+///         world.wait_frames(30).await;
+///         world
+///             .query(|mut doors: Query<&mut Door>| {
+///                 for mut door in &mut doors {
+///                     door.open = true;
+///                 }
+///             })
+///             .await;
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(start_cutscene);
+/// ```
+///
+/// Dropping [`AsyncTasks`] cancels its tasks.
+#[derive(Resource, Default)]
+pub struct AsyncTasks {
+    world: AsyncWorld,
+    tasks: Vec<Task<()>>,
+}
+
+impl AsyncTasks {
+    /// Spawns a task on the [`AsyncComputeTaskPool`], created by `task` from an [`AsyncWorld`].
+    pub fn spawn<Fut>(&mut self, task: impl FnOnce(AsyncWorld) -> Fut)
+    where
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let future = task(self.world.clone());
+        let task = AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(future);
+        self.tasks.push(task);
+    }
+
+    /// Returns the number of tasks which did not finish.
+    pub fn len(&self) -> usize {
+        self.tasks.iter().filter(|task| !task.is_finished()).count()
+    }
+
+    /// Returns `true` if every task finished.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Grants the world access requested by the [`AsyncTasks`] since the last time it ran, and drops
+/// the finished tasks.
+///
+/// Requests made while this system runs, for example by a task resuming after its previous
+/// request, are only handled the next time it runs. This way, each `await` on an [`AsyncWorld`]
+/// takes at least a frame.
+pub fn run_async_tasks(world: &mut World) {
+    let Some(mut tasks) = world.get_resource_mut::<AsyncTasks>() else {
+        return;
+    };
+    tasks.tasks.retain(|task| !task.is_finished());
+    let requests = mem::take(&mut *tasks.world.lock());
+    for request in requests {
+        request(world);
+    }
+}
+
+/// A handle to the [`World`] given to [`AsyncTasks`], through which they can access it at the next
+/// sync point.
+#[derive(Clone, Default)]
+pub struct AsyncWorld {
+    requests: Arc<Mutex<Vec<WorldRequest>>>,
+}
+
+struct Reply<R> {
+    value: Option<R>,
+    waker: Option<Waker>,
+}
+
+impl AsyncWorld {
+    fn lock(&self) -> MutexGuard<'_, Vec<WorldRequest>> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs `f` with exclusive access to the world at the next sync point, and returns its result.
+    pub async fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World) -> R + Send + 'static,
+    ) -> R {
+        let reply = Arc::new(Mutex::new(Reply {
+            value: None,
+            waker: None,
+        }));
+        let sender = reply.clone();
+        self.lock().push(Box::new(move |world: &mut World| {
+            let value = f(world);
+            let mut reply = sender.lock().unwrap_or_else(PoisonError::into_inner);
+            reply.value = Some(value);
+            if let Some(waker) = reply.waker.take() {
+                waker.wake();
+            }
+        }));
+        poll_fn(|cx| {
+            let mut reply = reply.lock().unwrap_or_else(PoisonError::into_inner);
+            match reply.value.take() {
+                Some(value) => Poll::Ready(value),
+                None => {
+                    reply.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+
+    /// Runs `f` with a [`Query`] at the next sync point, and returns its result.
+    pub async fn query<D, F, R>(&self, f: impl FnOnce(Query<D, F>) -> R + Send + 'static) -> R
+    where
+        D: QueryData + 'static,
+        F: QueryFilter + 'static,
+        R: Send + 'static,
+    {
+        self.run(move |world| f(world.query_filtered::<D, F>().query_mut(world)))
+            .await
+    }
+
+    /// Waits for the next sync point.
+    pub async fn next_frame(&self) {
+        self.run(|_| {}).await;
+    }
+
+    /// Waits for `frames` sync points.
+    pub async fn wait_frames(&self, frames: u32) {
+        for _ in 0..frames {
+            self.next_frame().await;
+        }
+    }
+
+    /// Waits until `condition` returns `true`, checking it once per sync point.
+    pub async fn wait_until(&self, mut condition: impl FnMut(&mut World) -> bool + Send + 'static) {
+        loop {
+            let (done, returned) = self.run(move |world| (condition(world), condition)).await;
+            if done {
+                return;
+            }
+            condition = returned;
+        }
+    }
+
+    /// Waits for the next event of type `E` sent after this is called, and returns it.
+    ///
+    /// If the event was not added with [`App::add_event`], this logs a warning and waits until it is.
+    pub async fn wait_for_event<E: Event + Clone>(&self) -> E {
+        let mut cursor = self
+            .run(|world| {
+                let cursor = world
+                    .get_resource::<Events<E>>()
+                    .map(Events::get_cursor_current);
+                if cursor.is_none() {
+                    warn!(
+                        "Waiting for the event {}, which was not added with `App::add_event`",
+                        core::any::type_name::<E>()
+                    );
+                }
+                cursor
+            })
+            .await;
+        loop {
+            let (event, returned) = self
+                .run(move |world| {
+                    let Some(events) = world.get_resource::<Events<E>>() else {
+                        return (None, cursor);
+                    };
+                    match cursor {
+                        Some(mut cursor) => (cursor.read(events).next().cloned(), Some(cursor)),
+                        None => (None, Some(events.get_cursor_current())),
+                    }
+                })
+                .await;
+            if let Some(event) = event {
+                return event;
+            }
+            cursor = returned;
+        }
+    }
+
+    /// Waits until the component `C` of `entity` is changed, returning `false` if the entity or its
+    /// component are removed first.
+    pub async fn wait_for_change<C: Component>(&self, entity: Entity) -> bool {
+        let since = self.run(World::change_tick).await;
+        loop {
+            let changed = self
+                .run(move |world| {
+                    let this_run = world.read_change_tick();
+                    let component = world.get_entity(entity).ok()?.get_ref::<C>()?;
+                    Some(component.last_changed().is_newer_than(since, this_run))
+                })
+                .await;
+            match changed {
+                Some(false) => {}
+                Some(true) => return true,
+                None => return false,
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{AsyncTaskPlugin, AsyncTasks, AsyncWorld};
+    use crate::{App, TaskPoolPlugin, Update};
+    use alloc::vec::Vec;
+    use bevy_ecs::{
+        component::Component,
+        event::Event,
+        resource::Resource,
+        system::{Query, ResMut},
+    };
+    use core::time::Duration;
+    use std::time::Instant;
+
+    #[derive(Component)]
+    struct Door {
+        open: bool,
+    }
+
+    #[derive(Event, Clone)]
+    struct LeverPulled;
+
+    #[derive(Resource, Default)]
+    struct Frame(u32);
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<(&'static str, u32)>);
+
+    async fn log(world: &AsyncWorld, message: &'static str) {
+        world
+            .run(move |world| {
+                let frame = world.resource::<Frame>().0;
+                world.resource_mut::<Log>().0.push((message, frame));
+            })
+            .await;
+    }
+
+    #[test]
+    fn async_tasks() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AsyncTaskPlugin))
+            .add_event::<LeverPulled>()
+            .init_resource::<Frame>()
+            .init_resource::<Log>()
+            .add_systems(Update, |mut frame: ResMut<Frame>| frame.0 += 1);
+        let door = app.world_mut().spawn(Door { open: false }).id();
+
+        let mut tasks = app.world_mut().resource_mut::<AsyncTasks>();
+        tasks.spawn(|world| async move {
+            world.wait_for_event::<LeverPulled>().await;
+            log(&world, "lever").await;
+            world.wait_frames(2).await;
+            world
+                .query(|mut doors: Query<&mut Door>| {
+                    for mut door in &mut doors {
+                        door.open = true;
+                    }
+                })
+                .await;
+            log(&world, "open").await;
+        });
+        tasks.spawn(move |world| async move {
+            assert!(world.wait_for_change::<Door>(door).await);
+            log(&world, "changed").await;
+        });
+
+        let timeout = Instant::now() + Duration::from_secs(10);
+        while !app.world().resource::<AsyncTasks>().is_empty() {
+            assert!(Instant::now() < timeout, "the async tasks did not finish");
+            if app.world().resource::<Frame>().0 == 5 {
+                app.world_mut().send_event(LeverPulled);
+            }
+            app.update();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert!(app.world().get::<Door>(door).unwrap().open);
+        let log = &app.world().resource::<Log>().0;
+        let frame = |message| log.iter().find(|(logged, _)| *logged == message).unwrap().1;
+        assert_eq!(log.len(), 3);
+        // Each await takes at least a frame.
+        assert!(frame("lever") > 5);
+        assert!(frame("open") >= frame("lever") + 3);
+        assert!(frame("changed") >= frame("lever") + 3);
+    }
+
+    #[test]
+    fn wait_for_event_not_added_yet() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AsyncTaskPlugin))
+            .init_resource::<Frame>()
+            .init_resource::<Log>()
+            .add_systems(Update, |mut frame: ResMut<Frame>| frame.0 += 1);
+
+        app.world_mut()
+            .resource_mut::<AsyncTasks>()
+            .spawn(|world| async move {
+                world.wait_for_event::<LeverPulled>().await;
+                log(&world, "lever").await;
+            });
+
+        let timeout = Instant::now() + Duration::from_secs(10);
+        while !app.world().resource::<AsyncTasks>().is_empty() {
+            assert!(Instant::now() < timeout, "the async task did not finish");
+            match app.world().resource::<Frame>().0 {
+                5 => {
+                    app.add_event::<LeverPulled>();
+                }
+                10 => {
+                    app.world_mut().send_event(LeverPulled);
+                }
+                _ => {}
+            }
+            app.update();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let log = &app.world().resource::<Log>().0;
+        assert_eq!(log.len(), 1);
+        assert!(log[0].1 > 10);
+    }
+}
@@ -24,6 +24,8 @@ extern crate alloc;
 extern crate self as bevy_app;
 
 mod app;
+#[cfg(feature = "bevy_tasks")]
+mod async_task;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
@@ -34,8 +36,12 @@ mod sub_app;
 mod task_pool_plugin;
 #[cfg(all(any(unix, windows), feature = "std"))]
 mod terminal_ctrl_c_handler;
+#[cfg(feature = "std")]
+mod threaded_sub_app;
 
 pub use app::*;
+#[cfg(feature = "bevy_tasks")]
+pub use async_task::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
@@ -46,6 +52,8 @@ pub use sub_app::*;
 pub use task_pool_plugin::*;
 #[cfg(all(any(unix, windows), feature = "std"))]
 pub use terminal_ctrl_c_handler::*;
+#[cfg(feature = "std")]
+pub use threaded_sub_app::*;
 
 /// The app prelude.
 ///
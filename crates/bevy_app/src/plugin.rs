@@ -1,5 +1,9 @@
 use crate::App;
-use core::any::Any;
+use alloc::{string::String, vec::Vec};
+use core::{
+    any::{Any, TypeId},
+    fmt,
+};
 use downcast_rs::{impl_downcast, Downcast};
 
 /// A collection of Bevy app logic and configuration.
@@ -21,6 +25,40 @@ use downcast_rs::{impl_downcast, Downcast};
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// ## Dependencies
+///
+/// A plugin can declare the plugins it depends on in [`Plugin::dependencies`]. The dependencies
+/// are checked when [`App::run`] is called, which panics with the list of unsatisfied
+/// dependencies and the [plugin graph](App::plugin_graph) instead of failing later on, deep
+/// inside of a system.
+///
+/// ```
+/// # use bevy_app::*;
+/// # struct AssetPlugin;
+/// # impl Plugin for AssetPlugin {
+/// #     fn build(&self, app: &mut App) {}
+/// # }
+/// # struct AudioPlugin;
+/// # impl Plugin for AudioPlugin {
+/// #     fn build(&self, app: &mut App) {}
+/// # }
+/// pub struct SoundEffectsPlugin;
+///
+/// impl Plugin for SoundEffectsPlugin {
+///     fn build(&self, app: &mut App) {
+///         // ...
+///     }
+///
+///     fn dependencies(&self, dependencies: &mut PluginDependencies) {
+///         dependencies.require::<AssetPlugin>().optional::<AudioPlugin>();
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins((AssetPlugin, SoundEffectsPlugin))
+///     .run();
+/// ```
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -89,6 +127,11 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Declares the plugins this plugin depends on. See [`PluginDependencies`].
+    fn dependencies(&self, _dependencies: &mut PluginDependencies) {
+        // no dependencies
+    }
 }
 
 impl_downcast!(Plugin);
@@ -112,6 +155,176 @@ pub enum PluginsState {
     Cleaned,
 }
 
+/// The dependencies of a [`Plugin`], declared in [`Plugin::dependencies`].
+///
+/// Dependencies must be added to the [`App`] before the plugin that depends on them, so
+/// that they are built first. They are matched by type, so a dependency on a generic plugin
+/// is only satisfied by the same type parameters.
+#[derive(Default, Debug, Clone)]
+pub struct PluginDependencies {
+    dependencies: Vec<PluginDependency>,
+}
+
+impl PluginDependencies {
+    /// Requires the plugin `T` to be added to the [`App`] before this plugin.
+    pub fn require<T: Plugin>(&mut self) -> &mut Self {
+        self.dependencies.push(PluginDependency::new::<T>(false));
+        self
+    }
+
+    /// Requires the plugin `T` to be added to the [`App`] before this plugin, if it is added
+    /// at all.
+    pub fn optional<T: Plugin>(&mut self) -> &mut Self {
+        self.dependencies.push(PluginDependency::new::<T>(true));
+        self
+    }
+
+    /// Returns an iterator over the declared dependencies.
+    pub fn iter(&self) -> impl Iterator<Item = &PluginDependency> {
+        self.dependencies.iter()
+    }
+}
+
+/// A dependency declared with [`PluginDependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginDependency {
+    /// The [`TypeId`] of the plugin depended on.
+    pub type_id: TypeId,
+    /// The type name of the plugin depended on.
+    pub name: &'static str,
+    /// Whether the plugin depended on may be missing.
+    pub optional: bool,
+}
+
+impl PluginDependency {
+    fn new<T: Plugin>(optional: bool) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: core::any::type_name::<T>(),
+            optional,
+        }
+    }
+}
+
+/// An unsatisfied [`PluginDependency`], reported by [`PluginGraph::errors`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PluginDependencyError {
+    /// A required dependency was not added.
+    #[error("plugin {plugin} requires {dependency}, which was not added")]
+    Missing {
+        /// The name of the plugin declaring the dependency.
+        plugin: String,
+        /// The type name of the missing plugin.
+        dependency: &'static str,
+    },
+    /// A dependency was added after the plugin depending on it.
+    #[error("plugin {plugin} depends on {dependency}, which must be added before it")]
+    Order {
+        /// The name of the plugin declaring the dependency.
+        plugin: String,
+        /// The type name of the plugin added too late.
+        dependency: &'static str,
+    },
+}
+
+/// The plugins added to the main [`SubApp`](crate::SubApp) of an [`App`] and their
+/// dependencies, returned by [`App::plugin_graph`].
+///
+/// The [`Display`](fmt::Display) implementation dumps the plugins in the order they were built,
+/// with their dependencies.
+#[derive(Debug, Clone, Default)]
+pub struct PluginGraph {
+    nodes: Vec<PluginGraphNode>,
+}
+
+/// A plugin of a [`PluginGraph`].
+#[derive(Debug, Clone)]
+pub struct PluginGraphNode {
+    /// The [name](Plugin::name) of the plugin.
+    pub name: String,
+    /// The dependencies of the plugin, with the index of the [`PluginGraphNode`] satisfying
+    /// each of them, if any.
+    pub dependencies: Vec<(PluginDependency, Option<usize>)>,
+}
+
+impl PluginGraph {
+    /// Builds the graph of `plugins`, listed in the order they were built.
+    pub(crate) fn new<'a>(plugins: impl IntoIterator<Item = &'a (dyn Plugin + 'static)>) -> Self {
+        let plugins: Vec<_> = plugins.into_iter().collect();
+        let nodes = plugins
+            .iter()
+            .map(|plugin| {
+                let mut dependencies = PluginDependencies::default();
+                plugin.dependencies(&mut dependencies);
+                PluginGraphNode {
+                    name: plugin.name().into(),
+                    dependencies: dependencies
+                        .dependencies
+                        .into_iter()
+                        .map(|dependency| {
+                            let index = plugins
+                                .iter()
+                                .position(|p| (**p).as_any().type_id() == dependency.type_id);
+                            (dependency, index)
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+        Self { nodes }
+    }
+
+    /// Returns the plugins, in the order they were built.
+    pub fn nodes(&self) -> &[PluginGraphNode] {
+        &self.nodes
+    }
+
+    /// Returns the unsatisfied dependencies.
+    pub fn errors(&self) -> Vec<PluginDependencyError> {
+        let mut errors = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            for (dependency, dependency_index) in &node.dependencies {
+                match *dependency_index {
+                    None if !dependency.optional => errors.push(PluginDependencyError::Missing {
+                        plugin: node.name.clone(),
+                        dependency: dependency.name,
+                    }),
+                    Some(dependency_index) if dependency_index > index => {
+                        errors.push(PluginDependencyError::Order {
+                            plugin: node.name.clone(),
+                            dependency: dependency.name,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+        errors
+    }
+}
+
+impl fmt::Display for PluginGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, node) in self.nodes.iter().enumerate() {
+            writeln!(f, "{index}: {}", node.name)?;
+            for (dependency, dependency_index) in &node.dependencies {
+                let kind = if dependency.optional {
+                    "optional"
+                } else {
+                    "requires"
+                };
+                match dependency_index {
+                    Some(dependency_index) => {
+                        writeln!(f, "    {kind} {} ({dependency_index})", dependency.name)?;
+                    }
+                    None => writeln!(f, "    {kind} {} (not added)", dependency.name)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// A dummy plugin that's to temporarily occupy an entry in an app's plugin registry.
 pub(crate) struct PlaceholderPlugin;
 
@@ -3,12 +3,14 @@ use crate::{
     plugin::Plugin,
     PluginsState,
 };
+use alloc::boxed::Box;
+use bevy_ecs::world::World;
 use bevy_platform_support::time::Instant;
 use core::time::Duration;
 
 #[cfg(target_arch = "wasm32")]
 use {
-    alloc::{boxed::Box, rc::Rc},
+    alloc::rc::Rc,
     core::cell::RefCell,
     wasm_bindgen::{prelude::*, JsCast},
 };
@@ -74,15 +76,7 @@ impl Plugin for ScheduleRunnerPlugin {
     fn build(&self, app: &mut App) {
         let run_mode = self.run_mode;
         app.set_runner(move |mut app: App| {
-            let plugins_state = app.plugins_state();
-            if plugins_state != PluginsState::Cleaned {
-                while app.plugins_state() == PluginsState::Adding {
-                    #[cfg(all(not(target_arch = "wasm32"), feature = "bevy_tasks"))]
-                    bevy_tasks::tick_global_task_pools_on_main_thread();
-                }
-                app.finish();
-                app.cleanup();
-            }
+            finish_plugins(&mut app);
 
             match run_mode {
                 RunMode::Once => {
@@ -176,3 +170,238 @@ impl Plugin for ScheduleRunnerPlugin {
         });
     }
 }
+
+/// Waits for all plugins to be ready, then finishes and cleans them up, unless it was already done.
+fn finish_plugins(app: &mut App) {
+    let plugins_state = app.plugins_state();
+    if plugins_state != PluginsState::Cleaned {
+        while app.plugins_state() == PluginsState::Adding {
+            #[cfg(all(not(target_arch = "wasm32"), feature = "bevy_tasks"))]
+            bevy_tasks::tick_global_task_pools_on_main_thread();
+        }
+        app.finish();
+        app.cleanup();
+    }
+}
+
+/// Runs an [`App`] headlessly for a number of frames, or until a condition holds on its [`World`],
+/// and hands the final [`World`] back to the caller. This is primarily meant for integration tests.
+///
+/// Unlike [`App::run`], which hands the [`App`] over to its runner, [`HeadlessRunner::run`]
+/// returns a [`HeadlessRunOutput`] describing why the app stopped.
+///
+/// ```
+/// # use bevy_app::{prelude::*, HeadlessRunner, HeadlessStopReason};
+/// # use bevy_ecs::prelude::*;
+/// # use core::time::Duration;
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// let mut app = App::new();
+/// app.init_resource::<Score>()
+///     .add_systems(Update, |mut score: ResMut<Score>| score.0 += 10);
+///
+/// let output = HeadlessRunner::new()
+///     .until(|world| world.resource::<Score>().0 >= 50)
+///     .with_max_frames(100)
+///     .with_timeout(Duration::from_secs(10))
+///     .run(app);
+///
+/// assert_eq!(output.stop_reason, HeadlessStopReason::Condition);
+/// assert!(output.exit.is_success());
+/// assert_eq!(output.frames, 5);
+/// assert_eq!(output.world.resource::<Score>().0, 50);
+/// ```
+#[derive(Default)]
+pub struct HeadlessRunner {
+    max_frames: Option<u32>,
+    condition: Option<Box<dyn FnMut(&World) -> bool>>,
+    timeout: Option<Duration>,
+    frame_time: Option<Duration>,
+}
+
+/// Why a [`HeadlessRunner`] stopped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HeadlessStopReason {
+    /// The maximum number of frames was reached.
+    MaxFrames,
+    /// The condition set with [`HeadlessRunner::until`] holds.
+    Condition,
+    /// The app requested to exit with an [`AppExit`] event.
+    AppExit,
+    /// The timeout elapsed.
+    Timeout,
+}
+
+/// The result of [`HeadlessRunner::run`].
+#[derive(Debug)]
+pub struct HeadlessRunOutput {
+    /// The exit status of the app.
+    ///
+    /// This is the [`AppExit`] requested by the app, if any. Otherwise, it is
+    /// [`AppExit::Success`] if the condition set with [`HeadlessRunner::until`] holds, or if the
+    /// maximum number of frames was reached without any condition set, and [`AppExit::error`]
+    /// otherwise.
+    pub exit: AppExit,
+    /// Why the app stopped.
+    pub stop_reason: HeadlessStopReason,
+    /// The number of frames that were run.
+    pub frames: u32,
+    /// The main world of the app once it stopped.
+    pub world: World,
+}
+
+impl HeadlessRunner {
+    /// Creates a runner that runs until the app requests to exit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a runner that runs `frames` frames.
+    pub fn frames(frames: u32) -> Self {
+        Self::new().with_max_frames(frames)
+    }
+
+    /// Stops the app after `frames` frames.
+    #[must_use]
+    pub fn with_max_frames(mut self, frames: u32) -> Self {
+        self.max_frames = Some(frames);
+        self
+    }
+
+    /// Stops the app once `condition` holds, checked after each frame.
+    #[must_use]
+    pub fn until(mut self, condition: impl FnMut(&World) -> bool + 'static) -> Self {
+        self.condition = Some(Box::new(condition));
+        self
+    }
+
+    /// Stops the app once `timeout` of real time has elapsed.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Paces the frames so that each of them takes at least `frame_time`.
+    ///
+    /// Without the `std` feature, frames are not paced.
+    #[must_use]
+    pub fn with_frame_time(mut self, frame_time: Duration) -> Self {
+        self.frame_time = Some(frame_time);
+        self
+    }
+
+    /// Finishes the plugins of `app` and runs it until one of the exit criteria is met.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [dependencies](Plugin::dependencies) of a plugin are not satisfied.
+    pub fn run(mut self, mut app: App) -> HeadlessRunOutput {
+        app.assert_plugin_dependencies();
+        finish_plugins(&mut app);
+
+        let start_time = Instant::now();
+        let mut frames = 0;
+        let (exit, stop_reason) = loop {
+            if self
+                .max_frames
+                .is_some_and(|max_frames| frames >= max_frames)
+            {
+                let exit = match self.condition {
+                    Some(_) => AppExit::error(),
+                    None => AppExit::Success,
+                };
+                break (exit, HeadlessStopReason::MaxFrames);
+            }
+            if self
+                .timeout
+                .is_some_and(|timeout| start_time.elapsed() >= timeout)
+            {
+                break (AppExit::error(), HeadlessStopReason::Timeout);
+            }
+
+            let frame_start = Instant::now();
+            app.update();
+            frames += 1;
+
+            if let Some(exit) = app.should_exit() {
+                break (exit, HeadlessStopReason::AppExit);
+            }
+            if let Some(condition) = &mut self.condition {
+                if condition(app.world()) {
+                    break (AppExit::Success, HeadlessStopReason::Condition);
+                }
+            }
+
+            #[cfg(feature = "std")]
+            if let Some(remaining) = self
+                .frame_time
+                .and_then(|frame_time| frame_time.checked_sub(frame_start.elapsed()))
+            {
+                std::thread::sleep(remaining);
+            }
+            #[cfg(not(feature = "std"))]
+            let _ = frame_start;
+        };
+
+        HeadlessRunOutput {
+            exit,
+            stop_reason,
+            frames,
+            world: core::mem::take(app.world_mut()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeadlessRunner, HeadlessStopReason};
+    use crate::{App, AppExit, Update};
+    use bevy_ecs::{event::EventWriter, resource::Resource, system::ResMut};
+    use core::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    fn count(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn headless_runner() {
+        let mut app = App::new();
+        app.init_resource::<Counter>().add_systems(Update, count);
+        let output = HeadlessRunner::frames(3).run(app);
+        assert_eq!(output.stop_reason, HeadlessStopReason::MaxFrames);
+        assert_eq!(output.exit, AppExit::Success);
+        assert_eq!(output.world.resource::<Counter>().0, 3);
+
+        let mut app = App::new();
+        app.init_resource::<Counter>().add_systems(Update, count);
+        let output = HeadlessRunner::frames(3)
+            .until(|world| world.resource::<Counter>().0 > 5)
+            .run(app);
+        assert_eq!(output.stop_reason, HeadlessStopReason::MaxFrames);
+        assert_eq!(output.exit, AppExit::error());
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, count)
+            .add_systems(Update, |mut exit: EventWriter<AppExit>| {
+                exit.send(AppExit::from_code(2));
+            });
+        let output = HeadlessRunner::new().run(app);
+        assert_eq!(output.stop_reason, HeadlessStopReason::AppExit);
+        assert_eq!(output.exit, AppExit::from_code(2));
+        assert_eq!(output.frames, 1);
+
+        let output = HeadlessRunner::new()
+            .with_timeout(Duration::from_millis(20))
+            .with_frame_time(Duration::from_millis(5))
+            .run(App::new());
+        assert_eq!(output.stop_reason, HeadlessStopReason::Timeout);
+        assert!(output.exit.is_error());
+        assert!(output.frames >= 1);
+    }
+}
@@ -64,6 +64,9 @@ pub struct SubApp {
     world: World,
     /// List of plugins that have been added.
     pub(crate) plugin_registry: Vec<Box<dyn Plugin>>,
+    /// The indices of the plugins in [`plugin_registry`](Self::plugin_registry), in the order
+    /// they finished building.
+    pub(crate) plugin_build_order: Vec<usize>,
     /// The names of plugins that have been added to this app. (used to track duplicates and
     /// already-registered plugins)
     pub(crate) plugin_names: HashSet<String>,
@@ -90,6 +93,7 @@ impl Default for SubApp {
         Self {
             world,
             plugin_registry: Vec::default(),
+            plugin_build_order: Vec::default(),
             plugin_names: HashSet::default(),
             plugin_build_depth: 0,
             plugins_state: PluginsState::Adding,
@@ -495,11 +499,16 @@ pub struct SubApps {
     pub main: SubApp,
     /// Other, labeled sub-apps.
     pub sub_apps: HashMap<InternedAppLabel, SubApp>,
+    /// Labeled sub-apps running on their own thread.
+    #[cfg(feature = "std")]
+    pub threaded_sub_apps: HashMap<InternedAppLabel, crate::ThreadedSubApp>,
 }
 
 impl SubApps {
     /// Calls [`update`](SubApp::update) for the main sub-app, and then calls
-    /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest.
+    /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest. The
+    /// [threaded sub-apps](crate::ThreadedSubApp) are only extracted into, as they are updated
+    /// on their own thread.
     pub fn update(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_update_span = info_span!("update").entered();
@@ -514,6 +523,10 @@ impl SubApps {
             sub_app.extract(&mut self.main.world);
             sub_app.update();
         }
+        #[cfg(feature = "std")]
+        for threaded_sub_app in self.threaded_sub_apps.values_mut() {
+            threaded_sub_app.sync(&mut self.main.world);
+        }
 
         self.main.world.clear_trackers();
     }
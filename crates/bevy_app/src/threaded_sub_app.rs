@@ -0,0 +1,332 @@
+use crate::{App, AppLabel, InternedAppLabel, PluginsState, SubApp};
+use alloc::{format, sync::Arc, vec::Vec};
+use bevy_ecs::{resource::Resource, world::World};
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use std::{
+    sync::{mpsc, Mutex, MutexGuard, PoisonError},
+    thread::JoinHandle,
+    time::Instant,
+};
+
+#[cfg(feature = "trace")]
+use tracing::info_span;
+
+/// The minimum time the thread of a [`ThreadedSubApp`] sleeps between two ticks, with the sub-app
+/// unlocked, so that [`App::update`] can always lock it to sync, even with a zero tick interval.
+const MIN_TICK_GAP: Duration = Duration::from_micros(100);
+
+/// A [`SubApp`] running on its own thread at its own tick rate, inserted with
+/// [`App::insert_threaded_sub_app`].
+///
+/// The thread is started by the first [`App::update`], after which the sub-app is updated every
+/// `tick_interval` independently of the main app. Every [`App::update`] then acts as a sync
+/// point: once the main schedule has run, the [extract function](SubApp::set_extract) of the
+/// sub-app is called with both worlds, in between two ticks of the sub-app. Messages can be sent
+/// between the two worlds at any time through a [`sub_app_channel`].
+///
+/// The thread sleeps at least 100µs between two ticks, which bounds the tick rate of a sub-app
+/// with a zero or tiny `tick_interval` but lets the main app lock it in between.
+///
+/// The thread is stopped when the [`ThreadedSubApp`] is dropped, along with its [`App`].
+pub struct ThreadedSubApp {
+    label: InternedAppLabel,
+    sub_app: Arc<Mutex<SubApp>>,
+    tick_interval: Duration,
+    thread: Option<(JoinHandle<()>, Arc<AtomicBool>)>,
+}
+
+impl ThreadedSubApp {
+    pub(crate) fn new(label: InternedAppLabel, sub_app: SubApp, tick_interval: Duration) -> Self {
+        Self {
+            label,
+            sub_app: Arc::new(Mutex::new(sub_app)),
+            tick_interval,
+            thread: None,
+        }
+    }
+
+    /// Locks the sub-app, blocking until its current tick, if any, has finished. The sub-app
+    /// does not tick while the returned guard is alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thread of the sub-app panicked.
+    pub fn lock(&self) -> MutexGuard<'_, SubApp> {
+        self.sub_app.lock().unwrap_or_else(|_| {
+            panic!("The thread of the sub-app '{:?}' panicked.", self.label);
+        })
+    }
+
+    /// Returns the time between the start of two ticks of the sub-app.
+    pub fn tick_interval(&self) -> Duration {
+        self.tick_interval
+    }
+
+    /// Returns `true` if the thread of the sub-app was started.
+    pub fn is_running(&self) -> bool {
+        self.thread.is_some()
+    }
+
+    /// Extracts data from `main_world` into the sub-app, starting its thread if needed.
+    pub(crate) fn sync(&mut self, main_world: &mut World) {
+        {
+            let mut sub_app = self.lock();
+            if self.thread.is_none() {
+                if sub_app.plugins_state() < PluginsState::Finished {
+                    sub_app.finish();
+                }
+                if sub_app.plugins_state() < PluginsState::Cleaned {
+                    sub_app.cleanup();
+                }
+            }
+            sub_app.extract(main_world);
+        }
+        if self.thread.is_none() {
+            self.start();
+        }
+    }
+
+    fn start(&mut self) {
+        let sub_app = self.sub_app.clone();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let tick_interval = self.tick_interval;
+        let _label = self.label;
+        let thread = std::thread::Builder::new()
+            .name(format!("sub-app {:?}", self.label))
+            .spawn(move || {
+                while !thread_stop.load(Ordering::Acquire) {
+                    let start = Instant::now();
+                    {
+                        #[cfg(feature = "trace")]
+                        let _sub_app_span =
+                            info_span!("threaded sub app", name = ?_label).entered();
+                        sub_app
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .update();
+                    }
+                    let remaining = tick_interval.saturating_sub(start.elapsed());
+                    std::thread::sleep(remaining.max(MIN_TICK_GAP));
+                }
+            })
+            .expect("failed to spawn the thread of a sub-app");
+        self.thread = Some((thread, stop));
+    }
+
+    fn stop(&mut self) {
+        if let Some((thread, stop)) = self.thread.take() {
+            stop.store(true, Ordering::Release);
+            // A panic of the thread was already reported by the thread itself.
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ThreadedSubApp {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl App {
+    /// Inserts a [`SubApp`] with the given label, which is updated on its own thread every
+    /// `tick_interval`. See [`ThreadedSubApp`].
+    ///
+    /// The plugins of the sub-app are finished and cleaned up on the first [`App::update`],
+    /// before its thread starts. The sub-app must have an
+    /// [`update_schedule`](SubApp::update_schedule) to do anything on its thread.
+    ///
+    /// ```no_run
+    /// # use bevy_app::{prelude::*, sub_app_channel, AppLabel, SubApp};
+    /// # use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+    /// # use core::time::Duration;
+    /// #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+    /// struct PathfindingApp;
+    ///
+    /// struct PathFound(Entity);
+    ///
+    /// let (sender, receiver) = sub_app_channel::<PathFound>();
+    ///
+    /// let mut pathfinding = SubApp::new();
+    /// pathfinding.update_schedule = Some(Main.intern());
+    /// pathfinding.insert_resource(sender).add_systems(Main, find_paths);
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(receiver)
+    ///     .insert_threaded_sub_app(PathfindingApp, pathfinding, Duration::from_millis(50));
+    /// # fn find_paths() {}
+    /// ```
+    pub fn insert_threaded_sub_app(
+        &mut self,
+        label: impl AppLabel,
+        sub_app: SubApp,
+        tick_interval: Duration,
+    ) {
+        let label = label.intern();
+        self.sub_apps
+            .threaded_sub_apps
+            .insert(label, ThreadedSubApp::new(label, sub_app, tick_interval));
+    }
+
+    /// Returns a reference to the [`ThreadedSubApp`] with the given label, if it exists.
+    pub fn get_threaded_sub_app(&self, label: impl AppLabel) -> Option<&ThreadedSubApp> {
+        self.sub_apps.threaded_sub_apps.get(&label.intern())
+    }
+
+    /// Removes the [`ThreadedSubApp`] with the given label, if it exists, stopping its thread.
+    pub fn remove_threaded_sub_app(&mut self, label: impl AppLabel) -> Option<SubApp> {
+        let mut threaded = self.sub_apps.threaded_sub_apps.remove(&label.intern())?;
+        threaded.stop();
+        let sub_app = Arc::try_unwrap(core::mem::take(&mut threaded.sub_app)).ok()?;
+        Some(sub_app.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+/// Creates a channel to send messages of type `M` between the worlds of an [`App`] and of its
+/// [`ThreadedSubApp`]s. Both ends are [`Resource`]s, usually inserted in different worlds.
+pub fn sub_app_channel<M: Send + 'static>() -> (SubAppSender<M>, SubAppReceiver<M>) {
+    let (sender, receiver) = mpsc::channel();
+    (SubAppSender(sender), SubAppReceiver(Mutex::new(receiver)))
+}
+
+/// The sending end of a [`sub_app_channel`]. It can be cloned to send messages from several
+/// worlds.
+#[derive(Resource, Debug)]
+pub struct SubAppSender<M: Send + 'static>(mpsc::Sender<M>);
+
+impl<M: Send + 'static> Clone for SubAppSender<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<M: Send + 'static> SubAppSender<M> {
+    /// Sends `message`, returning `false` if the [`SubAppReceiver`] was dropped.
+    pub fn send(&self, message: M) -> bool {
+        self.0.send(message).is_ok()
+    }
+}
+
+/// The receiving end of a [`sub_app_channel`].
+#[derive(Resource, Debug)]
+pub struct SubAppReceiver<M: Send + 'static>(Mutex<mpsc::Receiver<M>>);
+
+impl<M: Send + 'static> SubAppReceiver<M> {
+    /// Returns the next message, if any was sent, without blocking.
+    pub fn try_recv(&self) -> Option<M> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_recv()
+            .ok()
+    }
+
+    /// Returns all of the messages sent so far, without blocking.
+    pub fn drain(&self) -> Vec<M> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sub_app_channel, SubAppReceiver, SubAppSender};
+    use crate::{App, AppLabel, Main, SubApp};
+    use alloc::vec::Vec;
+    use bevy_ecs::{
+        resource::Resource,
+        schedule::ScheduleLabel,
+        system::{Res, ResMut},
+    };
+    use core::time::Duration;
+    use std::time::Instant;
+
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+    struct SimulationApp;
+
+    #[derive(Resource, Default)]
+    struct Frame(u32);
+
+    #[test]
+    fn threaded_sub_app() {
+        let (sender, receiver) = sub_app_channel::<u32>();
+
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Main.intern());
+        sub_app
+            .init_resource::<Frame>()
+            .insert_resource(sender)
+            .add_systems(Main, |frame: Res<Frame>, sender: Res<SubAppSender<u32>>| {
+                sender.send(frame.0);
+            })
+            .set_extract(|main_world, sub_world| {
+                sub_world.resource_mut::<Frame>().0 = main_world.resource::<Frame>().0;
+            });
+
+        let mut app = App::new();
+        app.init_resource::<Frame>()
+            .insert_resource(receiver)
+            .add_systems(Main, |mut frame: ResMut<Frame>| frame.0 += 1)
+            .insert_threaded_sub_app(SimulationApp, sub_app, Duration::from_millis(1));
+        assert!(!app
+            .get_threaded_sub_app(SimulationApp)
+            .unwrap()
+            .is_running());
+
+        let timeout = Instant::now() + Duration::from_secs(10);
+        let mut received = Vec::new();
+        while !received.contains(&3) {
+            assert!(Instant::now() < timeout, "the sub-app did not tick");
+            if app.world().resource::<Frame>().0 < 3 {
+                app.update();
+            }
+            received.extend(app.world().resource::<SubAppReceiver<u32>>().drain());
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let threaded = app.get_threaded_sub_app(SimulationApp).unwrap();
+        assert!(threaded.is_running());
+        assert_eq!(threaded.lock().world().resource::<Frame>().0, 3);
+        // The sub-app only sees the frames it was synced with.
+        assert!(received.windows(2).all(|frames| frames[0] <= frames[1]));
+
+        let sub_app = app.remove_threaded_sub_app(SimulationApp).unwrap();
+        assert_eq!(sub_app.world().resource::<Frame>().0, 3);
+    }
+
+    #[test]
+    fn zero_tick_interval_does_not_starve_sync() {
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Main.intern());
+        sub_app
+            .init_resource::<Frame>()
+            .add_systems(Main, || std::thread::sleep(Duration::from_micros(50)))
+            .set_extract(|main_world, sub_world| {
+                sub_world.resource_mut::<Frame>().0 = main_world.resource::<Frame>().0;
+            });
+
+        let mut app = App::new();
+        app.init_resource::<Frame>()
+            .add_systems(Main, |mut frame: ResMut<Frame>| frame.0 += 1)
+            .insert_threaded_sub_app(SimulationApp, sub_app, Duration::ZERO);
+
+        let start = Instant::now();
+        for _ in 0..100 {
+            app.update();
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "the sync was starved"
+        );
+
+        let threaded = app.get_threaded_sub_app(SimulationApp).unwrap();
+        assert_eq!(threaded.lock().world().resource::<Frame>().0, 100);
+    }
+}
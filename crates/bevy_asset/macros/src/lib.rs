@@ -122,3 +122,122 @@ fn derive_dependency_visitor_internal(
         }
     })
 }
+
+const ASSET_ATTRIBUTE: &str = "asset";
+
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs with named fields",
+        ));
+    };
+
+    let mut field_loaders = Vec::new();
+    let mut field_visitors = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let name = ident.to_string();
+
+        let mut default_path: Option<syn::LitStr> = None;
+        let mut skip = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    default_path = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported asset attribute, expected `path` or `skip`"))
+                }
+            })?;
+        }
+
+        if skip {
+            field_loaders.push(quote!(#ident: ::core::default::Default::default()));
+            continue;
+        }
+
+        let entry = match default_path {
+            Some(default_path) => quote! {
+                manifest.take(#name).unwrap_or_else(|| {
+                    #bevy_asset_path::AssetCollectionEntry::Path(::core::convert::From::from(#default_path))
+                })
+            },
+            None => quote! {
+                manifest
+                    .take(#name)
+                    .ok_or(#bevy_asset_path::AssetCollectionError::MissingField(#name))?
+            },
+        };
+        field_loaders.push(quote! {
+            #ident: <#ty as #bevy_asset_path::FromAssetCollectionEntry>::from_entry(#name, #entry, load_context)?
+        });
+        field_visitors.push(quote! {
+            #bevy_asset_path::VisitAssetDependencies::visit_dependencies(&self.#ident, visit);
+        });
+    }
+
+    // prevent unused variable warnings in case every field is skipped
+    let (visit, manifest, load_context) = if field_visitors.is_empty() {
+        (
+            quote! { _visit },
+            quote! { _manifest },
+            quote! { _load_context },
+        )
+    } else {
+        (
+            quote! { visit },
+            quote! { manifest },
+            quote! { load_context },
+        )
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::Asset for #struct_name #type_generics #where_clause { }
+
+        impl #impl_generics #bevy_asset_path::VisitAssetDependencies for #struct_name #type_generics #where_clause {
+            fn visit_dependencies(&self, #visit: &mut impl FnMut(#bevy_asset_path::UntypedAssetId)) {
+                #(#field_visitors)*
+            }
+        }
+
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load_from_manifest(
+                #manifest: &mut #bevy_asset_path::AssetCollectionManifest,
+                #load_context: &mut #bevy_asset_path::LoadContext,
+            ) -> ::core::result::Result<Self, #bevy_asset_path::AssetCollectionError> {
+                ::core::result::Result::Ok(Self {
+                    #(#field_loaders,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,200 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::marker::PhantomData;
+
+use crate::{io::Reader, Asset, AssetLoader, Handle, LoadContext};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// A typed set of asset handles that is loaded as a single [`Asset`] from a manifest file.
+///
+/// Loading a collection loads every asset listed in its manifest as a dependency of the collection, so
+/// [`AssetServer::recursive_dependency_load_state`] (or [`AssetServer::is_loaded_with_dependencies`]) on the collection
+/// handle tracks the whole collection. This makes it easy to gate a game state on "all level assets are ready".
+///
+/// This trait is usually derived, which also implements [`Asset`] (so do not derive [`Asset`] as well).
+/// Every field of the struct is filled from the entry of the same name in the manifest. Fields can declare a default
+/// path with `#[asset(path = "...")]`, which is used when the manifest has no entry for them, and fields that are
+/// not asset handles can opt out with `#[asset(skip)]`, in which case they are initialized with [`Default`].
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, Handle};
+/// # use bevy_reflect::TypePath;
+/// # #[derive(bevy_asset::Asset, TypePath)] struct Image;
+/// # #[derive(bevy_asset::Asset, TypePath)] struct AudioSource;
+/// #[derive(AssetCollection, TypePath)]
+/// struct LevelAssets {
+///     #[asset(path = "textures/player.png")]
+///     player: Handle<Image>,
+///     tiles: Vec<Handle<Image>>,
+///     music: Handle<AudioSource>,
+/// }
+/// ```
+///
+/// A manifest for this collection (for example `levels/one.assets.ron`) could look like this:
+///
+/// ```ron
+/// {
+///     "tiles": ["tiles/grass.png", "tiles/water.png"],
+///     "music": "audio/level_one.ogg",
+/// }
+/// ```
+///
+/// Register the collection with [`AssetApp::init_asset_collection`](crate::AssetApp::init_asset_collection),
+/// then load it like any other asset with [`AssetServer::load`].
+///
+/// [`AssetServer::recursive_dependency_load_state`]: crate::AssetServer::recursive_dependency_load_state
+/// [`AssetServer::is_loaded_with_dependencies`]: crate::AssetServer::is_loaded_with_dependencies
+/// [`AssetServer::load`]: crate::AssetServer::load
+pub trait AssetCollection: Asset + Sized {
+    /// Builds the collection from the entries of `manifest`, loading the listed assets with `load_context`.
+    ///
+    /// Implementations should remove the entries they consume with [`AssetCollectionManifest::take`], so that
+    /// [`AssetCollectionLoader`] can report manifest entries that do not correspond to any field.
+    fn load_from_manifest(
+        manifest: &mut AssetCollectionManifest,
+        load_context: &mut LoadContext,
+    ) -> Result<Self, AssetCollectionError>;
+}
+
+/// The deserialized contents of an [`AssetCollection`] manifest: a map from field names to asset paths.
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(transparent)]
+pub struct AssetCollectionManifest {
+    entries: BTreeMap<String, AssetCollectionEntry>,
+}
+
+impl AssetCollectionManifest {
+    /// Parses a manifest from RON `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ron::error::SpannedError> {
+        ron::de::from_bytes(bytes)
+    }
+
+    /// Removes and returns the entry for `field`, if the manifest has one.
+    pub fn take(&mut self, field: &str) -> Option<AssetCollectionEntry> {
+        self.entries.remove(field)
+    }
+
+    /// Returns the names of the entries that have not been taken yet.
+    pub fn remaining(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
+
+/// A single entry of an [`AssetCollectionManifest`].
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AssetCollectionEntry {
+    /// A single asset path.
+    Path(String),
+    /// A list of asset paths.
+    Paths(Vec<String>),
+}
+
+/// A field of an [`AssetCollection`] that can be loaded from an [`AssetCollectionEntry`].
+pub trait FromAssetCollectionEntry: Sized {
+    /// Loads the assets listed in `entry` for the collection field named `field`.
+    fn from_entry(
+        field: &'static str,
+        entry: AssetCollectionEntry,
+        load_context: &mut LoadContext,
+    ) -> Result<Self, AssetCollectionError>;
+}
+
+impl<A: Asset> FromAssetCollectionEntry for Handle<A> {
+    fn from_entry(
+        field: &'static str,
+        entry: AssetCollectionEntry,
+        load_context: &mut LoadContext,
+    ) -> Result<Self, AssetCollectionError> {
+        match entry {
+            AssetCollectionEntry::Path(path) => Ok(load_context.load(path)),
+            AssetCollectionEntry::Paths(_) => Err(AssetCollectionError::ExpectedSinglePath(field)),
+        }
+    }
+}
+
+impl<A: Asset> FromAssetCollectionEntry for Vec<Handle<A>> {
+    fn from_entry(
+        _field: &'static str,
+        entry: AssetCollectionEntry,
+        load_context: &mut LoadContext,
+    ) -> Result<Self, AssetCollectionError> {
+        let paths = match entry {
+            AssetCollectionEntry::Path(path) => vec![path],
+            AssetCollectionEntry::Paths(paths) => paths,
+        };
+        Ok(paths
+            .into_iter()
+            .map(|path| load_context.load(path))
+            .collect())
+    }
+}
+
+/// An error that occurs while loading an [`AssetCollection`].
+#[derive(Error, Debug)]
+pub enum AssetCollectionError {
+    /// An I/O error occurred while reading the manifest.
+    #[error("Could not read the asset collection manifest: {0}")]
+    Io(#[from] std::io::Error),
+    /// The manifest is not valid RON.
+    #[error("Could not parse the asset collection manifest: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    /// A field has no entry in the manifest and does not declare a default path.
+    #[error("The asset collection field `{0}` has no entry in the manifest and no default path")]
+    MissingField(&'static str),
+    /// A field holding a single handle was given a list of paths.
+    #[error(
+        "The asset collection field `{0}` expects a single path, but the manifest lists several"
+    )]
+    ExpectedSinglePath(&'static str),
+    /// The manifest has entries that do not correspond to any field of the collection.
+    #[error("The asset collection manifest has entries that do not match any field: {0:?}")]
+    UnknownFields(Vec<String>),
+}
+
+/// An [`AssetLoader`] that loads an [`AssetCollection`] from a RON manifest with the `.assets.ron` extension.
+///
+/// This is registered by [`AssetApp::init_asset_collection`](crate::AssetApp::init_asset_collection).
+pub struct AssetCollectionLoader<C: AssetCollection> {
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: AssetCollection> Default for AssetCollectionLoader<C> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: AssetCollection> AssetLoader for AssetCollectionLoader<C> {
+    type Asset = C;
+    type Settings = ();
+    type Error = AssetCollectionError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut manifest = AssetCollectionManifest::from_bytes(&bytes)?;
+        let collection = C::load_from_manifest(&mut manifest, load_context)?;
+        let unknown_fields: Vec<String> = manifest.remaining().map(ToString::to_string).collect();
+        if !unknown_fields.is_empty() {
+            return Err(AssetCollectionError::UnknownFields(unknown_fields));
+        }
+        Ok(collection)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["assets.ron"]
+    }
+}
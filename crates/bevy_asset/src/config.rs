@@ -0,0 +1,244 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{
+    io::Reader, Asset, AssetApp, AssetEvent, AssetId, AssetLoader, AssetPath, AssetServer, Assets,
+    Handle, LoadContext,
+};
+use bevy_app::{App, PreUpdate};
+use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, GetTypeRegistration, PartialReflect, Reflect, TypePath,
+};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+use tracing::{error, info};
+
+/// The format of a [`ConfigFile`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigFormat {
+    /// [RON](ron), loaded from `.config.ron` files.
+    Ron,
+    /// JSON, loaded from `.config.json` files. Requires the `json_config` feature.
+    #[cfg(feature = "json_config")]
+    Json,
+}
+
+/// The raw contents of a configuration file, bound to a [`Resource`] with
+/// [`ConfigResourceApp::init_config_resource`].
+///
+/// The contents are only deserialized when they are applied to the resource, because that is when the type of the
+/// resource is known.
+#[derive(Asset, TypePath, Debug)]
+pub struct ConfigFile {
+    /// The format of [`ConfigFile::bytes`].
+    pub format: ConfigFormat,
+    /// The contents of the file.
+    pub bytes: Vec<u8>,
+}
+
+impl ConfigFile {
+    /// Deserializes the contents of this file as the reflected type `T`.
+    pub fn deserialize<T: GetTypeRegistration>(
+        &self,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<alloc::boxed::Box<dyn PartialReflect>, ConfigError> {
+        let type_registry = type_registry.read();
+        let registration = type_registry
+            .get(core::any::TypeId::of::<T>())
+            .ok_or(ConfigError::UnregisteredType)?;
+        let deserializer = TypedReflectDeserializer::new(registration, &type_registry);
+        match self.format {
+            ConfigFormat::Ron => {
+                let mut ron_deserializer = ron::de::Deserializer::from_bytes(&self.bytes)?;
+                deserializer
+                    .deserialize(&mut ron_deserializer)
+                    .map_err(|err| ConfigError::Ron(ron_deserializer.span_error(err)))
+            }
+            #[cfg(feature = "json_config")]
+            ConfigFormat::Json => {
+                let mut json_deserializer = serde_json::Deserializer::from_slice(&self.bytes);
+                Ok(deserializer.deserialize(&mut json_deserializer)?)
+            }
+        }
+    }
+}
+
+/// An error that occurs when applying a [`ConfigFile`] to a [`Resource`].
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    /// The resource type is not registered in the [`AppTypeRegistry`].
+    #[error("the resource type is not registered")]
+    UnregisteredType,
+    /// The RON contents could not be deserialized.
+    #[error(transparent)]
+    Ron(#[from] ron::error::SpannedError),
+    /// The JSON contents could not be deserialized.
+    #[cfg(feature = "json_config")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// The deserialized value could not be applied to the resource.
+    #[error(transparent)]
+    Apply(#[from] bevy_reflect::ApplyError),
+}
+
+/// Loads [`ConfigFile`]s from `.config.ron` files, and from `.config.json` files with the `json_config` feature.
+#[derive(Default)]
+pub struct ConfigFileLoader;
+
+impl AssetLoader for ConfigFileLoader {
+    type Asset = ConfigFile;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<ConfigFile, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let format = match load_context.path().extension() {
+            #[cfg(feature = "json_config")]
+            Some(extension) if extension == "json" => ConfigFormat::Json,
+            _ => ConfigFormat::Ron,
+        };
+        Ok(ConfigFile { format, bytes })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[
+            "config.ron",
+            #[cfg(feature = "json_config")]
+            "config.json",
+        ]
+    }
+}
+
+/// The [`ConfigFile`] bound to the resource `R` by [`ConfigResourceApp::init_config_resource`].
+#[derive(Resource)]
+pub struct ConfigResourceHandle<R: Resource> {
+    /// The handle of the bound file.
+    pub handle: Handle<ConfigFile>,
+    marker: PhantomData<fn() -> R>,
+}
+
+/// An event sent when the [`ConfigFile`] bound to the resource `R` could not be applied to it.
+///
+/// The resource is left unchanged.
+#[derive(Event, Debug)]
+pub struct ConfigApplyFailedEvent<R: Resource> {
+    /// The id of the file that failed to apply.
+    pub id: AssetId<ConfigFile>,
+    /// The path of the file that failed to apply, if it has one.
+    pub path: Option<AssetPath<'static>>,
+    /// Why the file failed to apply.
+    pub error: ConfigError,
+    marker: PhantomData<fn() -> R>,
+}
+
+/// Extension trait for [`App`] to bind reflected resources to configuration files.
+pub trait ConfigResourceApp {
+    /// Initializes the resource `R` and binds it to the [`ConfigFile`] at `path`.
+    ///
+    /// The file is loaded at startup and applied to the resource once loaded, and again every time it is
+    /// hot reloaded (see [`AssetPlugin::watch_for_changes_override`](crate::AssetPlugin::watch_for_changes_override)).
+    /// The file contents are deserialized with reflection and applied like [`ReflectResource::apply`], so fields
+    /// that are missing from a struct keep their current value. Deserialization errors are logged, leave the
+    /// resource unchanged and send a [`ConfigApplyFailedEvent`].
+    ///
+    /// ```no_run
+    /// # use bevy_app::App;
+    /// # use bevy_asset::{AssetPlugin, ConfigResourceApp};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Resource, Reflect, Default)]
+    /// struct PlayerTuning {
+    ///     speed: f32,
+    ///     jump_height: f32,
+    /// }
+    ///
+    /// // `assets/tuning/player.config.ron` contains `(speed: 5.0, jump_height: 2.0)`.
+    /// App::new()
+    ///     .add_plugins(AssetPlugin::default())
+    ///     .init_config_resource::<PlayerTuning>("tuning/player.config.ron");
+    /// ```
+    ///
+    /// [`ReflectResource::apply`]: bevy_ecs::reflect::ReflectResource::apply
+    fn init_config_resource<R>(&mut self, path: impl Into<AssetPath<'static>>) -> &mut Self
+    where
+        R: Resource + Reflect + GetTypeRegistration + FromWorld;
+}
+
+impl ConfigResourceApp for App {
+    fn init_config_resource<R>(&mut self, path: impl Into<AssetPath<'static>>) -> &mut Self
+    where
+        R: Resource + Reflect + GetTypeRegistration + FromWorld,
+    {
+        if !self.world().contains_resource::<Assets<ConfigFile>>() {
+            self.init_asset::<ConfigFile>()
+                .init_asset_loader::<ConfigFileLoader>();
+        }
+        let handle = self.world().resource::<AssetServer>().load(path);
+        self.register_type::<R>()
+            .init_resource::<R>()
+            .insert_resource(ConfigResourceHandle::<R> {
+                handle,
+                marker: PhantomData,
+            })
+            .add_event::<ConfigApplyFailedEvent<R>>()
+            .add_systems(PreUpdate, apply_config_resource::<R>)
+    }
+}
+
+/// Applies the [`ConfigFile`] bound to `R` whenever it is loaded or modified.
+pub fn apply_config_resource<R: Resource + Reflect + GetTypeRegistration>(
+    mut events: EventReader<AssetEvent<ConfigFile>>,
+    config: Res<ConfigResourceHandle<R>>,
+    files: Res<Assets<ConfigFile>>,
+    type_registry: Res<AppTypeRegistry>,
+    mut resource: ResMut<R>,
+    mut failures: EventWriter<ConfigApplyFailedEvent<R>>,
+) {
+    let id = config.handle.id();
+    let mut changed = false;
+    for event in events.read() {
+        changed |= event.is_loaded_with_dependencies(id) || event.is_modified(id);
+    }
+    if !changed {
+        return;
+    }
+    let Some(file) = files.get(id) else {
+        return;
+    };
+    let path = config.handle.path();
+    // `try_apply` can fail halfway through, so the resource is restored from a copy on failure
+    // and only marked as changed once the whole file was applied.
+    let previous = resource.clone_value();
+    let target = resource.bypass_change_detection();
+    match file
+        .deserialize::<R>(&type_registry)
+        .and_then(|value| Ok(target.try_apply(value.as_ref())?))
+    {
+        Ok(()) => {
+            resource.set_changed();
+            if let Some(path) = path {
+                info!("Applied configuration file {path}.");
+            }
+        }
+        Err(err) => {
+            target.apply(previous.as_ref());
+            match path {
+                Some(path) => error!("Failed to apply configuration file {path}: {err}"),
+                None => error!("Failed to apply configuration file: {err}"),
+            }
+            failures.send(ConfigApplyFailedEvent {
+                id,
+                path: path.cloned(),
+                error: err,
+                marker: PhantomData,
+            });
+        }
+    }
+}
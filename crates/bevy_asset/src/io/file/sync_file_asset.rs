@@ -1,9 +1,9 @@
-use futures_io::{AsyncRead, AsyncWrite};
+use futures_io::{AsyncRead, AsyncSeek, AsyncWrite, SeekFrom};
 use futures_lite::Stream;
 
 use crate::io::{
     get_meta_path, AssetReader, AssetReaderError, AssetWriter, AssetWriterError, AsyncSeekForward,
-    PathStream, Reader, Writer,
+    PathStream, Reader, ReaderNotSeekableError, SeekableReader, Writer,
 };
 
 use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
@@ -38,12 +38,23 @@ impl AsyncSeekForward for FileReader {
     ) -> Poll<std::io::Result<u64>> {
         let this = self.get_mut();
         let current = this.0.stream_position()?;
-        let seek = this.0.seek(std::io::SeekFrom::Start(current + offset));
+        let seek = this.0.seek(SeekFrom::Start(current + offset));
 
         Poll::Ready(seek)
     }
 }
 
+impl AsyncSeek for FileReader {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let this = self.get_mut();
+        Poll::Ready(this.0.seek(pos))
+    }
+}
+
 impl Reader for FileReader {
     fn read_to_end<'a>(
         &'a mut self,
@@ -52,6 +63,10 @@ impl Reader for FileReader {
     {
         stackfuture::StackFuture::from(async { self.0.read_to_end(buf) })
     }
+
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        Ok(self)
+    }
 }
 
 struct FileWriter(File);
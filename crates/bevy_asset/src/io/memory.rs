@@ -1,8 +1,11 @@
-use crate::io::{AssetReader, AssetReaderError, PathStream, Reader};
+use crate::io::{
+    slice_seek_position, AssetReader, AssetReaderError, PathStream, Reader, ReaderNotSeekableError,
+    SeekableReader,
+};
 use alloc::{borrow::ToOwned, boxed::Box, sync::Arc, vec::Vec};
 use bevy_platform_support::collections::HashMap;
 use core::{pin::Pin, task::Poll};
-use futures_io::AsyncRead;
+use futures_io::{AsyncRead, AsyncSeek, SeekFrom};
 use futures_lite::{ready, Stream};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
@@ -269,6 +272,18 @@ impl AsyncSeekForward for DataReader {
     }
 }
 
+impl AsyncSeek for DataReader {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let new_pos = slice_seek_position(self.bytes_read, self.data.value().len(), pos)?;
+        self.bytes_read = new_pos;
+        Poll::Ready(Ok(new_pos as _))
+    }
+}
+
 impl Reader for DataReader {
     fn read_to_end<'a>(
         &'a mut self,
@@ -285,6 +300,10 @@ impl Reader for DataReader {
             }
         })
     }
+
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        Ok(self)
+    }
 }
 
 impl AssetReader for MemoryAssetReader {
@@ -328,7 +347,11 @@ impl AssetReader for MemoryAssetReader {
 
 #[cfg(test)]
 pub mod test {
-    use super::Dir;
+    use super::{Dir, MemoryAssetReader};
+    use crate::io::{AssetReader, Reader};
+    use alloc::vec::Vec;
+    use futures_io::SeekFrom;
+    use futures_lite::AsyncSeekExt;
     use std::path::Path;
 
     #[test]
@@ -362,4 +385,26 @@ pub mod test {
         assert_eq!(meta.path(), b_path);
         assert_eq!(meta.value(), b_meta);
     }
+
+    #[test]
+    fn memory_reader_seek_and_range() {
+        let dir = Dir::default();
+        let path = Path::new("a.bin");
+        dir.insert_asset(path, b"0123456789".to_vec());
+        let reader = MemoryAssetReader { root: dir };
+
+        bevy_tasks::block_on(async {
+            let mut data_reader = reader.read(path).await.unwrap();
+            let seekable = data_reader.seekable().unwrap();
+            assert_eq!(seekable.seek(SeekFrom::End(-3)).await.unwrap(), 7);
+            let mut bytes = Vec::new();
+            seekable.read_to_end(&mut bytes).await.unwrap();
+            assert_eq!(bytes, b"789");
+            assert_eq!(seekable.seek(SeekFrom::Current(-5)).await.unwrap(), 5);
+            assert!(seekable.seek(SeekFrom::Current(-6)).await.is_err());
+
+            assert_eq!(reader.read_range_bytes(path, 2..5).await.unwrap(), b"234");
+            assert_eq!(reader.read_range_bytes(path, 8..20).await.unwrap(), b"89");
+        });
+    }
 }
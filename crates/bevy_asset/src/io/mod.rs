@@ -26,10 +26,11 @@ use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::future::Future;
 use core::{
     mem::size_of,
+    ops::Range,
     pin::Pin,
     task::{Context, Poll},
 };
-use futures_io::{AsyncRead, AsyncWrite};
+use futures_io::{AsyncRead, AsyncSeek, AsyncWrite, SeekFrom};
 use futures_lite::{ready, Stream};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -159,6 +160,10 @@ impl<S: AsyncSeekForward + Unpin + ?Sized> Future for SeekForwardFuture<'_, S> {
 /// This is essentially a trait alias for types implementing [`AsyncRead`] and [`AsyncSeekForward`].
 /// The only reason a blanket implementation is not provided for applicable types is to allow
 /// implementors to override the provided implementation of [`Reader::read_to_end`].
+///
+/// Readers that support random access expose it through [`Reader::seekable`]. All of the readers returned by
+/// Bevy's built-in [`AssetReader`] implementations (including the embedded and memory sources) are seekable, which
+/// allows loaders to decode large assets lazily instead of reading them into memory up front.
 pub trait Reader: AsyncRead + AsyncSeekForward + Unpin + Send + Sync {
     /// Reads the entire contents of this reader and appends them to a vec.
     ///
@@ -173,6 +178,15 @@ pub trait Reader: AsyncRead + AsyncSeekForward + Unpin + Send + Sync {
         let future = futures_lite::AsyncReadExt::read_to_end(self, buf);
         StackFuture::from(future)
     }
+
+    /// Returns this reader as a [`SeekableReader`], if it supports arbitrary seeking.
+    ///
+    /// # Note for implementors
+    /// The provided implementation returns [`ReaderNotSeekableError`]. Readers that implement [`AsyncSeek`]
+    /// should override it to return `Ok(self)`.
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        Err(ReaderNotSeekableError)
+    }
 }
 
 impl Reader for Box<dyn Reader + '_> {
@@ -182,6 +196,75 @@ impl Reader for Box<dyn Reader + '_> {
     ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
         (**self).read_to_end(buf)
     }
+
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        (**self).seekable()
+    }
+}
+
+/// A [`Reader`] that also implements [`AsyncSeek`], allowing random access to the asset bytes.
+///
+/// Use [`Reader::seekable`] to obtain one from a [`Reader`]. This is automatically implemented for every
+/// type implementing both traits.
+pub trait SeekableReader: Reader + AsyncSeek {}
+
+impl<T: Reader + AsyncSeek> SeekableReader for T {}
+
+/// An error returned by [`Reader::seekable`] when the reader does not support arbitrary seeking.
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+#[error("The reader does not support seeking")]
+pub struct ReaderNotSeekableError;
+
+impl From<ReaderNotSeekableError> for std::io::Error {
+    fn from(value: ReaderNotSeekableError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::Unsupported, value)
+    }
+}
+
+/// Reads the bytes in `range` from `reader`. This will use [`AsyncSeek`] if the reader is seekable
+/// and fall back to [`AsyncSeekForward`] otherwise.
+///
+/// The returned bytes are truncated if `range` extends past the end of the reader.
+pub async fn read_range(reader: &mut dyn Reader, range: Range<u64>) -> std::io::Result<Vec<u8>> {
+    use futures_lite::{AsyncReadExt, AsyncSeekExt};
+
+    let len = range.end.saturating_sub(range.start);
+    let mut bytes = Vec::new();
+    match reader.seekable() {
+        Ok(seekable) => {
+            seekable.seek(SeekFrom::Start(range.start)).await?;
+            seekable.take(len).read_to_end(&mut bytes).await?;
+        }
+        Err(_) => {
+            reader.seek_forward(range.start).await?;
+            reader.take(len).read_to_end(&mut bytes).await?;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Computes the new position of a [`SeekFrom`] operation on an in-memory buffer of length `len`
+/// whose cursor is currently at `position`.
+pub(crate) fn slice_seek_position(
+    position: usize,
+    len: usize,
+    pos: SeekFrom,
+) -> std::io::Result<usize> {
+    let new_pos = match pos {
+        SeekFrom::Start(offset) => usize::try_from(offset).ok(),
+        SeekFrom::End(offset) => isize::try_from(offset)
+            .ok()
+            .and_then(|offset| len.checked_add_signed(offset)),
+        SeekFrom::Current(offset) => isize::try_from(offset)
+            .ok()
+            .and_then(|offset| position.checked_add_signed(offset)),
+    };
+    new_pos.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "seek position is out of range",
+        )
+    })
 }
 
 /// A future that returns a value or an [`AssetReaderError`]
@@ -254,6 +337,21 @@ pub trait AssetReader: Send + Sync + 'static {
             Ok(meta_bytes)
         }
     }
+    /// Reads the asset bytes in `range` at the given `path` into a [`Vec<u8>`]. The returned bytes are truncated
+    /// if `range` extends past the end of the asset.
+    ///
+    /// The provided implementation seeks within the reader returned by [`AssetReader::read`]. Sources that can
+    /// fetch ranges more efficiently (for example with HTTP range requests) should override it.
+    fn read_range_bytes<'a>(
+        &'a self,
+        path: &'a Path,
+        range: Range<u64>,
+    ) -> impl ConditionalSendFuture<Output = Result<Vec<u8>, AssetReaderError>> {
+        async move {
+            let mut reader = self.read(path).await?;
+            Ok(read_range(&mut reader, range).await?)
+        }
+    }
 }
 
 /// Equivalent to an [`AssetReader`] but using boxed futures, necessary eg. when using a `dyn AssetReader`,
@@ -285,6 +383,12 @@ pub trait ErasedAssetReader: Send + Sync + 'static {
         &'a self,
         path: &'a Path,
     ) -> BoxedFuture<'a, Result<Vec<u8>, AssetReaderError>>;
+    /// Reads the asset bytes in `range` at the given `path` into a [`Vec<u8>`]. See [`AssetReader::read_range_bytes`].
+    fn read_range_bytes<'a>(
+        &'a self,
+        path: &'a Path,
+        range: Range<u64>,
+    ) -> BoxedFuture<'a, Result<Vec<u8>, AssetReaderError>>;
 }
 
 impl<T: AssetReader> ErasedAssetReader for T {
@@ -324,6 +428,13 @@ impl<T: AssetReader> ErasedAssetReader for T {
     ) -> BoxedFuture<'a, Result<Vec<u8>, AssetReaderError>> {
         Box::pin(Self::read_meta_bytes(self, path))
     }
+    fn read_range_bytes<'a>(
+        &'a self,
+        path: &'a Path,
+        range: Range<u64>,
+    ) -> BoxedFuture<'a, Result<Vec<u8>, AssetReaderError>> {
+        Box::pin(Self::read_range_bytes(self, path, range))
+    }
 }
 
 pub type Writer = dyn AsyncWrite + Unpin + Send + Sync;
@@ -670,6 +781,18 @@ impl AsyncSeekForward for VecReader {
     }
 }
 
+impl AsyncSeek for VecReader {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let new_pos = slice_seek_position(self.bytes_read, self.bytes.len(), pos)?;
+        self.bytes_read = new_pos;
+        Poll::Ready(Ok(new_pos as _))
+    }
+}
+
 impl Reader for VecReader {
     fn read_to_end<'a>(
         &'a mut self,
@@ -686,6 +809,10 @@ impl Reader for VecReader {
             }
         })
     }
+
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        Ok(self)
+    }
 }
 
 /// An [`AsyncRead`] implementation capable of reading a [`&[u8]`].
@@ -744,6 +871,18 @@ impl<'a> AsyncSeekForward for SliceReader<'a> {
     }
 }
 
+impl AsyncSeek for SliceReader<'_> {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let new_pos = slice_seek_position(self.bytes_read, self.bytes.len(), pos)?;
+        self.bytes_read = new_pos;
+        Poll::Ready(Ok(new_pos as _))
+    }
+}
+
 impl Reader for SliceReader<'_> {
     fn read_to_end<'a>(
         &'a mut self,
@@ -760,6 +899,10 @@ impl Reader for SliceReader<'_> {
             }
         })
     }
+
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        Ok(self)
+    }
 }
 
 /// Appends `.meta` to the given path.
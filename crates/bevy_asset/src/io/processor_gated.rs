@@ -10,7 +10,7 @@ use futures_io::AsyncRead;
 use std::path::Path;
 use tracing::trace;
 
-use super::{AsyncSeekForward, ErasedAssetReader};
+use super::{AsyncSeekForward, ErasedAssetReader, ReaderNotSeekableError, SeekableReader};
 
 /// An [`AssetReader`] that will prevent asset (and asset metadata) read futures from returning for a
 /// given path until that path has been processed by [`AssetProcessor`].
@@ -159,4 +159,9 @@ impl Reader for TransactionLockedReader<'_> {
     ) -> stackfuture::StackFuture<'a, std::io::Result<usize>, { super::STACK_FUTURE_SIZE }> {
         self.reader.read_to_end(buf)
     }
+
+    fn seekable(&mut self) -> Result<&mut dyn SeekableReader, ReaderNotSeekableError> {
+        // The returned reader borrows `self`, so the transaction lock is held for as long as it is used.
+        self.reader.seekable()
+    }
 }
@@ -167,13 +167,15 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
-        Asset, AssetApp, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer, Assets,
-        DirectAssetAccessExt, Handle, UntypedHandle,
+        Asset, AssetApp, AssetCollection, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer,
+        Assets, DirectAssetAccessExt, Handle, UntypedHandle,
     };
 }
 
 mod asset_changed;
 mod assets;
+mod collection;
+mod config;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -182,12 +184,15 @@ mod id;
 mod loader;
 mod loader_builders;
 mod path;
+mod progress;
 mod reflect;
 mod render_asset;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::Asset;
+pub use bevy_asset_macros::{Asset, AssetCollection};
+pub use collection::*;
+pub use config::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -199,6 +204,7 @@ pub use loader_builders::{
     Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
 };
 pub use path::*;
+pub use progress::*;
 pub use reflect::*;
 pub use render_asset::*;
 pub use server::*;
@@ -396,6 +402,11 @@ impl Plugin for AssetPlugin {
             // This is virtually never a real problem: asset loading is async and so anything that interacts directly with it
             // needs to be robust to stochastic delays anyways.
             .add_systems(PreUpdate, handle_internal_asset_events.ambiguous_with_all())
+            .init_resource::<LoadingProgress>()
+            .add_systems(
+                PreUpdate,
+                update_loading_progress.after(handle_internal_asset_events),
+            )
             .register_type::<AssetPath>();
     }
 }
@@ -512,6 +523,9 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Initializes the given [`AssetCollection`] type as an [`Asset`] (see [`AssetApp::init_asset`]) and registers an
+    /// [`AssetCollectionLoader`] for it, which loads the collection from `.assets.ron` manifest files.
+    fn init_asset_collection<C: AssetCollection>(&mut self) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -592,6 +606,11 @@ impl AssetApp for App {
             .add_systems(PreUpdate, Assets::<A>::track_assets.in_set(TrackAssets))
     }
 
+    fn init_asset_collection<C: AssetCollection>(&mut self) -> &mut Self {
+        self.init_asset::<C>()
+            .register_asset_loader(AssetCollectionLoader::<C>::default())
+    }
+
     fn register_asset_reflect<A>(&mut self) -> &mut Self
     where
         A: Asset + Reflect + FromReflect + GetTypeRegistration,
@@ -638,8 +657,9 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets,
+        Asset, AssetApp, AssetCollection, AssetEvent, AssetId, AssetLoadError,
+        AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetServer, Assets, ConfigApplyFailedEvent,
+        ConfigError, ConfigResourceApp, ConfigResourceHandle,
     };
     use alloc::{
         boxed::Box,
@@ -651,13 +671,13 @@ mod tests {
     };
     use bevy_app::{App, TaskPoolPlugin, Update};
     use bevy_ecs::{
-        event::EventCursor,
+        event::{EventCursor, Events},
         prelude::*,
         schedule::{LogLevel, ScheduleBuildSettings},
     };
     use bevy_log::LogPlugin;
     use bevy_platform_support::collections::HashMap;
-    use bevy_reflect::TypePath;
+    use bevy_reflect::{Reflect, TypePath};
     use core::time::Duration;
     use serde::{Deserialize, Serialize};
     use std::path::Path;
@@ -1634,6 +1654,133 @@ mod tests {
         });
     }
 
+    #[derive(AssetCollection, TypePath)]
+    struct CoolTextCollection {
+        a: Handle<CoolText>,
+        #[asset(path = "b.cool.ron")]
+        b: Handle<CoolText>,
+        list: Vec<Handle<CoolText>>,
+        #[asset(skip)]
+        note: String,
+    }
+
+    #[test]
+    fn load_asset_collection() {
+        let dir = Dir::default();
+        let cool_ron = |text: &str| {
+            format!(
+                r#"(text: "{text}", dependencies: [], embedded_dependencies: [], sub_texts: [])"#
+            )
+        };
+        dir.insert_asset_text(Path::new("a.cool.ron"), &cool_ron("a"));
+        dir.insert_asset_text(Path::new("b.cool.ron"), &cool_ron("b"));
+        dir.insert_asset_text(Path::new("c.cool.ron"), &cool_ron("c"));
+        dir.insert_asset_text(
+            Path::new("level.assets.ron"),
+            r#"{ "a": "a.cool.ron", "list": ["b.cool.ron", "c.cool.ron"] }"#,
+        );
+        dir.insert_asset_text(
+            Path::new("unknown.assets.ron"),
+            r#"{ "a": "a.cool.ron", "list": [], "extra": "c.cool.ron" }"#,
+        );
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .init_asset_collection::<CoolTextCollection>()
+        .register_asset_loader(CoolTextLoader);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolTextCollection> = asset_server.load("level.assets.ron");
+        let unknown: Handle<CoolTextCollection> = asset_server.load("unknown.assets.ron");
+
+        run_app_until(&mut app, |world| {
+            let asset_server = world.resource::<AssetServer>();
+            (asset_server.is_loaded_with_dependencies(&handle)
+                && asset_server.load_state(&unknown).is_failed())
+            .then_some(())
+        });
+
+        let collection = get(app.world(), handle.id()).unwrap();
+        let text = |handle: &Handle<CoolText>| get(app.world(), handle.id()).unwrap().text.clone();
+        assert_eq!(text(&collection.a), "a");
+        assert_eq!(text(&collection.b), "b");
+        assert_eq!(
+            collection.list.iter().map(text).collect::<Vec<_>>(),
+            ["b", "c"]
+        );
+        assert!(collection.note.is_empty());
+    }
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    struct Tuning {
+        speed: f32,
+        lives: u32,
+    }
+
+    #[test]
+    fn config_resource() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("tuning.config.ron"), "(speed: 2.5, lives: 3)");
+        dir.insert_asset_text(Path::new("broken.config.ron"), r#"(speed: "fast")"#);
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_config_resource::<Tuning>("tuning.config.ron");
+
+        run_app_until(&mut app, |world| {
+            (*world.resource::<Tuning>() != Tuning::default()).then_some(())
+        });
+        assert_eq!(
+            *app.world().resource::<Tuning>(),
+            Tuning {
+                speed: 2.5,
+                lives: 3
+            }
+        );
+
+        // A file that fails to deserialize leaves the resource unchanged, and does not mark it as
+        // changed.
+        let last_changed = app.world().resource_ref::<Tuning>().last_changed();
+        let broken = app
+            .world()
+            .resource::<AssetServer>()
+            .load("broken.config.ron");
+        app.world_mut()
+            .resource_mut::<ConfigResourceHandle<Tuning>>()
+            .handle = broken.clone();
+        run_app_until(&mut app, |world| {
+            let failures = world.resource::<Events<ConfigApplyFailedEvent<Tuning>>>();
+            let failure = failures.iter_current_update_events().next()?;
+            assert_eq!(failure.id, broken.id());
+            assert_eq!(failure.path, Some(AssetPath::from("broken.config.ron")));
+            assert!(matches!(failure.error, ConfigError::Ron(_)));
+            Some(())
+        });
+        assert_eq!(
+            *app.world().resource::<Tuning>(),
+            Tuning {
+                speed: 2.5,
+                lives: 3
+            }
+        );
+        assert_eq!(
+            app.world().resource_ref::<Tuning>().last_changed(),
+            last_changed
+        );
+    }
+
     /// Tests that `AssetLoadFailedEvent<A>` events are emitted and can be used to retry failed assets.
     #[test]
     fn load_error_events() {
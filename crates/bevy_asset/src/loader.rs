@@ -1,5 +1,8 @@
 use crate::{
-    io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader},
+    io::{
+        read_range, AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError,
+        Reader,
+    },
     loader_builders::{Deferred, NestedLoader, StaticTyped},
     meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
     path::AssetPath,
@@ -16,7 +19,10 @@ use bevy_ecs::world::World;
 use bevy_log::warn;
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
-use core::any::{Any, TypeId};
+use core::{
+    any::{Any, TypeId},
+    ops::Range,
+};
 use downcast_rs::{impl_downcast, Downcast};
 use ron::error::SpannedError;
 use serde::{Deserialize, Serialize};
@@ -549,7 +555,29 @@ impl<'a> LoadContext<'a> {
         &'b mut self,
         path: impl Into<AssetPath<'c>>,
     ) -> Result<Vec<u8>, ReadAssetBytesError> {
-        let path = path.into();
+        self.read_asset_bytes_internal(path.into(), None).await
+    }
+
+    /// Reads the bytes in `range` of the asset at the given path. The returned bytes are truncated if `range`
+    /// extends past the end of the asset.
+    ///
+    /// This enables loaders to fetch parts of large companion files (such as binary buffers) lazily, instead of
+    /// reading them into memory in full. The asset is registered as a loader dependency just like with
+    /// [`LoadContext::read_asset_bytes`].
+    pub async fn read_asset_bytes_range<'b, 'c>(
+        &'b mut self,
+        path: impl Into<AssetPath<'c>>,
+        range: Range<u64>,
+    ) -> Result<Vec<u8>, ReadAssetBytesError> {
+        self.read_asset_bytes_internal(path.into(), Some(range))
+            .await
+    }
+
+    async fn read_asset_bytes_internal(
+        &mut self,
+        path: AssetPath<'_>,
+        range: Option<Range<u64>>,
+    ) -> Result<Vec<u8>, ReadAssetBytesError> {
         let source = self.asset_server.get_source(path.source())?;
         let asset_reader = match self.asset_server.mode() {
             AssetServerMode::Unprocessed { .. } => source.reader(),
@@ -569,14 +597,17 @@ impl<'a> LoadContext<'a> {
         } else {
             Default::default()
         };
-        let mut bytes = Vec::new();
-        reader
-            .read_to_end(&mut bytes)
-            .await
-            .map_err(|source| ReadAssetBytesError::Io {
-                path: path.path().to_path_buf(),
-                source,
-            })?;
+        let bytes = match range {
+            Some(range) => read_range(&mut reader, range).await,
+            None => {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await.map(|_| bytes)
+            }
+        }
+        .map_err(|source| ReadAssetBytesError::Io {
+            path: path.path().to_path_buf(),
+            source,
+        })?;
         self.loader_dependencies.insert(path.clone_owned(), hash);
         Ok(bytes)
     }
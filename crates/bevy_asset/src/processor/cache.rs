@@ -0,0 +1,237 @@
+use crate::meta::{get_asset_hash, AssetHash};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
+use thiserror::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    futures_io::ErrorKind,
+    std::path::{Path, PathBuf},
+};
+
+/// Uniquely identifies the output of processing a single asset.
+///
+/// The key is a hash of the source asset bytes, the source meta bytes (which contain the processor type name and its settings)
+/// and the processor's [`Process::VERSION`]. Two processing runs with the same key are expected to produce the same output,
+/// which is what allows a [`ProcessedAssetCache`] to be shared between machines.
+///
+/// [`Process::VERSION`]: crate::processor::Process::VERSION
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessCacheKey(pub AssetHash);
+
+impl ProcessCacheKey {
+    /// Computes the [`ProcessCacheKey`] for the given source `meta_bytes`, `asset_bytes` and `processor_version`.
+    ///
+    /// NOTE: changing the hashing logic here invalidates every existing cache entry.
+    pub fn new(meta_bytes: &[u8], asset_bytes: &[u8], processor_version: u32) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&get_asset_hash(meta_bytes, asset_bytes));
+        hasher.update(&processor_version.to_le_bytes());
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// Returns the lowercase hexadecimal representation of this key, suitable for use as a file name or URL segment.
+    pub fn to_hex(&self) -> String {
+        blake3::Hash::from(self.0).to_hex().as_str().into()
+    }
+}
+
+/// The cached result of processing an asset: the final processed asset bytes and the processed meta bytes.
+///
+/// The meta bytes include the [`ProcessedInfo`](crate::meta::ProcessedInfo) of the processed asset, which lists the
+/// process dependencies that were used to produce it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CachedProcessedAsset {
+    /// The processed asset bytes, as written by [`Process::process`](crate::processor::Process::process).
+    pub asset_bytes: Vec<u8>,
+    /// The serialized processed [`AssetMeta`](crate::meta::AssetMeta).
+    pub meta_bytes: Vec<u8>,
+}
+
+/// An error that occurs when reading from or writing to a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub enum ProcessedAssetCacheError {
+    /// A file-system-based error occurred while accessing the cache.
+    #[error("Encountered an I/O error while accessing the processed asset cache: {0}")]
+    Io(#[from] futures_io::Error),
+    /// A backend-specific error occurred, such as a failed request to a remote store.
+    #[error("Encountered an error in the processed asset cache backend: {0}")]
+    Backend(Box<dyn core::error::Error + Send + Sync + 'static>),
+}
+
+/// A content-addressed store of processed assets, keyed by [`ProcessCacheKey`].
+///
+/// When the [`AssetProcessor`](crate::processor::AssetProcessor) has a cache configured, it looks up the key of every asset
+/// it is about to process. On a hit, the cached output is written to the processed destination and
+/// [`Process::process`](crate::processor::Process::process) is skipped entirely. On a miss, the asset is processed as usual
+/// and the result is pushed to the cache.
+///
+/// This trait is not object safe, if needed use a dyn [`ErasedProcessedAssetCache`] instead.
+///
+/// Implement this trait to share processed assets through a remote store (for example a build server). A local directory
+/// implementation is provided by [`FileProcessedAssetCache`].
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Returns the cached processed asset for `key`, or [`None`] if the cache does not contain it.
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+    ) -> impl ConditionalSendFuture<
+        Output = Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>,
+    >;
+
+    /// Stores `asset` in the cache under `key`, replacing any previous value.
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> impl ConditionalSendFuture<Output = Result<(), ProcessedAssetCacheError>>;
+}
+
+/// Equivalent to a [`ProcessedAssetCache`] but using boxed futures, necessary eg. when using a `dyn ProcessedAssetCache`,
+/// as [`ProcessedAssetCache`] isn't currently object safe.
+pub trait ErasedProcessedAssetCache: Send + Sync + 'static {
+    /// Type-erased variant of [`ProcessedAssetCache::get`].
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>>;
+
+    /// Type-erased variant of [`ProcessedAssetCache::put`].
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>>;
+}
+
+impl<T: ProcessedAssetCache> ErasedProcessedAssetCache for T {
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>> {
+        Box::pin(Self::get(self, key))
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>> {
+        Box::pin(Self::put(self, key, asset))
+    }
+}
+
+/// A [`ProcessedAssetCache`] that stores processed assets in a local directory.
+///
+/// Each entry is stored as two files named after the hex representation of its [`ProcessCacheKey`]: the processed asset
+/// bytes, and the processed meta bytes with a `.meta` extension. The directory can be shared between machines
+/// (for example through a network drive).
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileProcessedAssetCache {
+    root_path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileProcessedAssetCache {
+    /// The default cache directory, relative to the project root. This sits next to the processor transaction log.
+    pub const DEFAULT_PATH: &'static str = "imported_assets/cache";
+
+    /// Creates a new [`FileProcessedAssetCache`] at a path relative to the project root (see
+    /// [`FileAssetReader::get_base_path`](crate::io::file::FileAssetReader::get_base_path)).
+    /// Absolute paths are used as-is.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            root_path: crate::io::file::get_base_path().join(path.as_ref()),
+        }
+    }
+
+    /// Returns the root directory where cached processed assets are stored.
+    pub fn root_path(&self) -> &PathBuf {
+        &self.root_path
+    }
+
+    fn asset_path(&self, key: &ProcessCacheKey) -> PathBuf {
+        self.root_path.join(key.to_hex())
+    }
+
+    fn meta_path(&self, key: &ProcessCacheKey) -> PathBuf {
+        let mut path = self.asset_path(key);
+        path.set_extension("meta");
+        path
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for FileProcessedAssetCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PATH)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ProcessedAssetCache for FileProcessedAssetCache {
+    async fn get<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+    ) -> Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError> {
+        // The meta file is written last, so its presence marks a complete entry.
+        let meta_bytes = match async_fs::read(self.meta_path(key)).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let asset_bytes = match async_fs::read(self.asset_path(key)).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Some(CachedProcessedAsset {
+            asset_bytes,
+            meta_bytes,
+        }))
+    }
+
+    async fn put<'a>(
+        &'a self,
+        key: &'a ProcessCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> Result<(), ProcessedAssetCacheError> {
+        async_fs::create_dir_all(&self.root_path).await?;
+        async_fs::write(self.asset_path(key), &asset.asset_bytes).await?;
+        async_fs::write(self.meta_path(key), &asset.meta_bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn cache_key_covers_all_inputs() {
+        let key = ProcessCacheKey::new(b"meta", b"asset", 0);
+        assert_eq!(key, ProcessCacheKey::new(b"meta", b"asset", 0));
+        assert_ne!(key, ProcessCacheKey::new(b"meta2", b"asset", 0));
+        assert_ne!(key, ProcessCacheKey::new(b"meta", b"asset2", 0));
+        assert_ne!(key, ProcessCacheKey::new(b"meta", b"asset", 1));
+        assert_eq!(key.to_hex().len(), 64);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn file_cache_round_trip() {
+        let root = crate::processor::tests::TempDir::new("file_cache_round_trip");
+        let cache = FileProcessedAssetCache::new(root.path());
+        let key = ProcessCacheKey::new(b"meta", b"asset", 0);
+        let asset = CachedProcessedAsset {
+            asset_bytes: vec![1, 2, 3],
+            meta_bytes: vec![4, 5],
+        };
+
+        let cache: &dyn ErasedProcessedAssetCache = &cache;
+        assert!(bevy_tasks::block_on(cache.get(&key)).unwrap().is_none());
+        bevy_tasks::block_on(cache.put(&key, &asset)).unwrap();
+        assert_eq!(bevy_tasks::block_on(cache.get(&key)).unwrap(), Some(asset));
+    }
+}
@@ -93,7 +93,11 @@ impl ProcessorTransactionLog {
     }
     /// Create a new, fresh log file. This will delete the previous log file if it exists.
     pub(crate) async fn new() -> Result<Self, futures_io::Error> {
-        let path = Self::full_log_path();
+        Self::new_at(Self::full_log_path()).await
+    }
+
+    /// Create a new, fresh log file at `path`. This will delete the previous log file if it exists.
+    pub(crate) async fn new_at(path: PathBuf) -> Result<Self, futures_io::Error> {
         match async_fs::remove_file(&path).await {
             Ok(_) => { /* successfully removed file */ }
             Err(err) => {
@@ -36,10 +36,20 @@
 //! - [`Process`]: a flexible low-level API for processing assets in arbitrary ways.
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
+//!
+//! # Caching processed assets
+//!
+//! Processed outputs can be shared between machines through a [`ProcessedAssetCache`], which stores them keyed by a
+//! [`ProcessCacheKey`] (a hash of the source bytes, the meta settings and the processor's [`Process::VERSION`]).
+//! On a cache hit, [`Process::process`] is skipped. Caching is disabled by default: use [`AssetProcessor::set_cache`] to
+//! enable it, for example with a [`FileProcessedAssetCache`] in `imported_assets/cache`, and [`AssetProcessor::disable_cache`]
+//! to turn it off again.
 
+mod cache;
 mod log;
 mod process;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
 
@@ -88,6 +98,9 @@ use {
 /// A [`ProcessorTransactionLog`] is produced, which uses "write-ahead logging" to make the [`AssetProcessor`] crash and failure resistant. If a failed/unfinished
 /// transaction from a previous run is detected, the affected asset(s) will be re-processed.
 ///
+/// If a [`ProcessedAssetCache`] is set with [`AssetProcessor::set_cache`], processed outputs are pushed to and fetched from it,
+/// which allows skipping [`Process::process`] for inputs that have already been processed elsewhere.
+///
 /// [`AssetProcessor`] can be cloned. It is backed by an [`Arc`] so clones will share state. Clones can be freely used in parallel.
 #[derive(Resource, Clone)]
 pub struct AssetProcessor {
@@ -102,6 +115,8 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    /// The cache used to skip processing of assets whose output is already known
+    cache: RwLock<Option<Arc<dyn ErasedProcessedAssetCache>>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -531,6 +546,23 @@ impl AssetProcessor {
         process_plans.insert(core::any::type_name::<P>(), Arc::new(processor));
     }
 
+    /// Sets the [`ProcessedAssetCache`] used to fetch and store processed assets, replacing the current one.
+    ///
+    /// No cache is used by default.
+    pub fn set_cache<C: ProcessedAssetCache>(&self, cache: C) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Disables the [`ProcessedAssetCache`]. Every changed asset will be processed with [`Process::process`].
+    pub fn disable_cache(&self) {
+        *self.data.cache.write() = None;
+    }
+
+    /// Returns the current [`ProcessedAssetCache`], if any.
+    pub fn get_cache(&self) -> Option<Arc<dyn ErasedProcessedAssetCache>> {
+        self.data.cache.read().clone()
+    }
+
     /// Set the default processor for the given `extension`. Make sure `P` is registered with [`AssetProcessor::register_processor`].
     pub fn set_default_processor<P: Process>(&self, extension: &str) {
         let mut default_processors = self.data.default_processors.write();
@@ -861,6 +893,18 @@ impl AssetProcessor {
                 }
             }
         }
+        let cache = processor.as_ref().and_then(|_| self.get_cache());
+        let cache_key = processor
+            .as_ref()
+            .map(|processor| ProcessCacheKey::new(&meta_bytes, &asset_bytes, processor.version()));
+        let cached = match (&cache, &cache_key) {
+            (Some(cache), Some(cache_key)) => {
+                self.get_cached_processed_asset(&**cache, cache_key, asset_path)
+                    .await
+            }
+            _ => None,
+        };
+
         // Note: this lock must remain alive until all processed asset and meta writes have finished (or failed)
         // See ProcessedAssetInfo::file_transaction_lock docs for more info
         let _transaction_lock = {
@@ -873,23 +917,63 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
-        if let Some(processor) = processor {
-            let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
-            let mut processed_meta = {
-                let mut context =
-                    ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
-                processor
-                    .process(&mut context, source_meta, &mut *writer)
-                    .await?
-            };
-
-            writer
-                .flush()
+        if let Some((cached, cached_processed_info)) = cached {
+            debug!("Using cached processed asset for {}", asset_path);
+            processed_writer
+                .write_bytes(path, &cached.asset_bytes)
                 .await
-                .map_err(|e| ProcessError::AssetWriterError {
-                    path: asset_path.clone(),
-                    err: AssetWriterError::Io(e),
-                })?;
+                .map_err(writer_err)?;
+            processed_writer
+                .write_meta_bytes(path, &cached.meta_bytes)
+                .await
+                .map_err(writer_err)?;
+            new_processed_info = cached_processed_info;
+        } else if let Some(processor) = processor {
+            // When caching, the processed bytes are buffered so they can be pushed to the cache afterwards.
+            let mut processed_bytes = None;
+            let mut processed_meta = if cache.is_some() {
+                let mut buffer = futures_lite::io::Cursor::new(Vec::new());
+                let processed_meta = {
+                    let mut context = ProcessContext::new(
+                        self,
+                        asset_path,
+                        &asset_bytes,
+                        &mut new_processed_info,
+                    );
+                    processor
+                        .process(&mut context, source_meta, &mut buffer)
+                        .await?
+                };
+                let bytes = buffer.into_inner();
+                processed_writer
+                    .write_bytes(path, &bytes)
+                    .await
+                    .map_err(writer_err)?;
+                processed_bytes = Some(bytes);
+                processed_meta
+            } else {
+                let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+                let processed_meta = {
+                    let mut context = ProcessContext::new(
+                        self,
+                        asset_path,
+                        &asset_bytes,
+                        &mut new_processed_info,
+                    );
+                    processor
+                        .process(&mut context, source_meta, &mut *writer)
+                        .await?
+                };
+
+                writer
+                    .flush()
+                    .await
+                    .map_err(|e| ProcessError::AssetWriterError {
+                        path: asset_path.clone(),
+                        err: AssetWriterError::Io(e),
+                    })?;
+                processed_meta
+            };
 
             let full_hash = get_full_asset_hash(
                 new_hash,
@@ -905,6 +989,18 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+
+            if let (Some(cache), Some(cache_key), Some(asset_bytes)) =
+                (cache, cache_key, processed_bytes)
+            {
+                let cached = CachedProcessedAsset {
+                    asset_bytes,
+                    meta_bytes,
+                };
+                if let Err(err) = cache.put(&cache_key, &cached).await {
+                    warn!("Failed to store processed asset {asset_path} in the cache: {err}");
+                }
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -922,6 +1018,46 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Fetches the processed asset for `cache_key` from `cache`, along with its [`ProcessedInfo`].
+    /// Entries whose process dependencies no longer match the current state of those dependencies are treated as misses,
+    /// as are entries that fail to load (the cache is an optimization, so failures are not fatal).
+    async fn get_cached_processed_asset(
+        &self,
+        cache: &dyn ErasedProcessedAssetCache,
+        cache_key: &ProcessCacheKey,
+        asset_path: &AssetPath<'static>,
+    ) -> Option<(CachedProcessedAsset, ProcessedInfo)> {
+        let cached = match cache.get(cache_key).await {
+            Ok(Some(cached)) => cached,
+            Ok(None) => return None,
+            Err(err) => {
+                warn!("Failed to read processed asset {asset_path} from the cache: {err}");
+                return None;
+            }
+        };
+        let processed_info = match ron::de::from_bytes::<ProcessedInfoMinimal>(&cached.meta_bytes) {
+            Ok(ProcessedInfoMinimal {
+                processed_info: Some(processed_info),
+            }) => processed_info,
+            Ok(_) => return None,
+            Err(err) => {
+                warn!("Cached processed meta for {asset_path} could not be deserialized: {err}");
+                return None;
+            }
+        };
+        let infos = self.data.asset_infos.read().await;
+        for dep_info in &processed_info.process_dependencies {
+            let live_hash = infos
+                .get(&dep_info.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dep_info.full_hash) {
+                return None;
+            }
+        }
+        Some((cached, processed_info))
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         if let Err(err) = ProcessorTransactionLog::validate().await {
             let state_is_valid = match err {
@@ -1029,6 +1165,7 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
         }
     }
 
@@ -1094,6 +1231,7 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const VERSION: u32 = T::VERSION;
 
     fn process(
         &self,
@@ -1424,3 +1562,160 @@ pub enum InitializeError {
     #[error("Failed to validate asset log: {0}")]
     ValidateLogError(#[from] ValidateLogError),
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        io::{
+            file::{FileAssetReader, FileAssetWriter},
+            Writer,
+        },
+        tests::CoolTextLoader,
+    };
+    use alloc::format;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A uniquely named directory in the system's temporary directory, removed when dropped.
+    pub(crate) struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub(crate) fn new(name: &str) -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let path = std::env::temp_dir().join(format!(
+                "bevy_asset_{name}_{}_{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        pub(crate) fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Copies the source asset bytes, counting how many times it ran.
+    struct CountingProcess(Arc<AtomicUsize>);
+
+    impl Process for CountingProcess {
+        type Settings = ();
+        type OutputLoader = CoolTextLoader;
+
+        async fn process(
+            &self,
+            context: &mut ProcessContext<'_>,
+            _meta: AssetMeta<(), Self>,
+            writer: &mut Writer,
+        ) -> Result<(), ProcessError> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            writer
+                .write_all(context.asset_bytes())
+                .await
+                .map_err(|err| ProcessError::AssetWriterError {
+                    path: context.path().clone_owned(),
+                    err: err.into(),
+                })
+        }
+    }
+
+    /// Processes `path` once with a fresh processor writing to `processed`, as a separate machine would.
+    fn process_with_cache(
+        source: &TempDir,
+        processed: &TempDir,
+        cache: &TempDir,
+        path: &Path,
+        runs: &Arc<AtomicUsize>,
+    ) -> ProcessResult {
+        let source_path = source.path().to_owned();
+        let processed_path = processed.path().to_owned();
+        let processed_writer_path = processed_path.clone();
+        let mut builders = AssetSourceBuilders::default();
+        builders.insert(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(FileAssetReader::new(source_path.clone())))
+                .with_writer({
+                    let source_path = source.path().to_owned();
+                    move |create_root| {
+                        Some(Box::new(FileAssetWriter::new(
+                            source_path.clone(),
+                            create_root,
+                        )))
+                    }
+                })
+                .with_processed_reader(move || {
+                    Box::new(FileAssetReader::new(processed_path.clone()))
+                })
+                .with_processed_writer(move |create_root| {
+                    Some(Box::new(FileAssetWriter::new(
+                        processed_writer_path.clone(),
+                        create_root,
+                    )))
+                }),
+        );
+        let processor = AssetProcessor::new(&mut builders);
+        processor.register_processor(CountingProcess(runs.clone()));
+        processor.set_default_processor::<CountingProcess>("cool.ron");
+        processor.set_cache(FileProcessedAssetCache::new(cache.path()));
+
+        bevy_tasks::block_on(async {
+            *processor.data.log.write().await = Some(
+                ProcessorTransactionLog::new_at(processed.path().join("log"))
+                    .await
+                    .unwrap(),
+            );
+            let source = processor.get_source(AssetSourceId::Default).unwrap();
+            let asset_path = AssetPath::from_path(path).clone_owned();
+            processor
+                .process_asset_internal(source, &asset_path)
+                .await
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn cache_hit_skips_processing() {
+        let source = TempDir::new("cache_hit_source");
+        let cache = TempDir::new("cache_hit_cache");
+        let path = Path::new("text.cool.ron");
+        let text = r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#;
+        std::fs::write(source.path().join(path), text).unwrap();
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let first = TempDir::new("cache_hit_first");
+        let ProcessResult::Processed(first_info) =
+            process_with_cache(&source, &first, &cache, path, &runs)
+        else {
+            panic!("Expected the asset to be processed");
+        };
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+
+        // A second processor sharing the cache, but none of the processed assets, reuses the cached output.
+        let second = TempDir::new("cache_hit_second");
+        let ProcessResult::Processed(second_info) =
+            process_with_cache(&source, &second, &cache, path, &runs)
+        else {
+            panic!("Expected the asset to be processed");
+        };
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+        assert_eq!(second_info.full_hash, first_info.full_hash);
+        for file in ["text.cool.ron", "text.cool.ron.meta"] {
+            assert_eq!(
+                std::fs::read(second.path().join(file)).unwrap(),
+                std::fs::read(first.path().join(file)).unwrap()
+            );
+        }
+        assert_eq!(
+            std::fs::read(second.path().join(path)).unwrap(),
+            text.as_bytes()
+        );
+    }
+}
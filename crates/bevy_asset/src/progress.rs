@@ -0,0 +1,211 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{AssetServer, RecursiveDependencyLoadState, UntypedHandle};
+use bevy_ecs::prelude::*;
+use bevy_platform_support::collections::HashMap;
+
+#[cfg(feature = "bevy_state")]
+use bevy_state::state::{FreelyMutableState, NextState};
+
+/// The amount of completed work out of a total, used by [`LoadingProgress`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of completed units of work.
+    pub done: u32,
+    /// The total number of units of work.
+    pub total: u32,
+}
+
+impl Progress {
+    /// Creates a new [`Progress`] with `done` out of `total` units of work completed.
+    pub const fn new(done: u32, total: u32) -> Self {
+        Self { done, total }
+    }
+
+    /// Returns the completed fraction of the work, between `0.0` and `1.0`.
+    /// Work with a `total` of zero is considered complete.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done.min(self.total) as f32 / self.total as f32
+        }
+    }
+
+    /// Returns `true` if all of the work is done.
+    pub fn is_complete(&self) -> bool {
+        self.done >= self.total
+    }
+}
+
+/// Tracks the loading progress of a set of assets and of custom work, such as shader compilation or procedural
+/// generation. This is intended to drive loading screens.
+///
+/// Assets are registered with [`LoadingProgress::track`] and count as done once they are loaded with all of their
+/// dependencies (see [`AssetServer::recursive_dependency_load_state`]). Custom work reports its [`Progress`] with
+/// [`LoadingProgress::set_custom`]. Every tracked asset and every custom contribution has the same weight in
+/// [`LoadingProgress::fraction`].
+///
+/// This resource is initialized by [`AssetPlugin`](crate::AssetPlugin) and updated in [`PreUpdate`](bevy_app::PreUpdate).
+/// With the `bevy_state` feature, [`transition_when_loaded`] can be used to move to the next state once
+/// everything is loaded.
+#[derive(Resource, Default, Debug)]
+pub struct LoadingProgress {
+    handles: Vec<UntypedHandle>,
+    loaded: u32,
+    failed: u32,
+    custom: HashMap<Cow<'static, str>, Progress>,
+}
+
+impl LoadingProgress {
+    /// Starts tracking the asset behind `handle`. The handle is kept alive until [`LoadingProgress::clear`] is called.
+    pub fn track(&mut self, handle: impl Into<UntypedHandle>) {
+        self.handles.push(handle.into());
+    }
+
+    /// Sets the [`Progress`] of the custom work named `name`.
+    pub fn set_custom(&mut self, name: impl Into<Cow<'static, str>>, progress: Progress) {
+        self.custom.insert(name.into(), progress);
+    }
+
+    /// Returns the [`Progress`] of the custom work named `name`, if any.
+    pub fn custom(&self, name: &str) -> Option<Progress> {
+        self.custom.get(name).copied()
+    }
+
+    /// Returns the number of tracked assets that are loaded with all of their dependencies, out of all tracked assets.
+    pub fn assets(&self) -> Progress {
+        Progress::new(self.loaded, self.handles.len() as u32)
+    }
+
+    /// Returns the number of tracked assets (or their dependencies) that failed to load.
+    pub fn failed(&self) -> u32 {
+        self.failed
+    }
+
+    /// Returns the overall completed fraction, between `0.0` and `1.0`.
+    /// If nothing is tracked, loading is considered complete.
+    pub fn fraction(&self) -> f32 {
+        let entries = self.handles.len() + self.custom.len();
+        if entries == 0 {
+            return 1.0;
+        }
+        let done = self.loaded as f32 + self.custom.values().map(Progress::fraction).sum::<f32>();
+        done / entries as f32
+    }
+
+    /// Returns the overall completed percentage, between `0.0` and `100.0`.
+    pub fn percent(&self) -> f32 {
+        self.fraction() * 100.0
+    }
+
+    /// Returns `true` if every tracked asset is loaded and every custom contribution is complete.
+    /// This is never `true` if a tracked asset failed to load.
+    pub fn is_complete(&self) -> bool {
+        self.failed == 0
+            && self.assets().is_complete()
+            && self.custom.values().all(Progress::is_complete)
+    }
+
+    /// Stops tracking all assets and custom work.
+    pub fn clear(&mut self) {
+        self.handles.clear();
+        self.custom.clear();
+        self.loaded = 0;
+        self.failed = 0;
+    }
+}
+
+/// Updates the tracked asset counts of [`LoadingProgress`].
+pub fn update_loading_progress(
+    asset_server: Res<AssetServer>,
+    mut loading_progress: ResMut<LoadingProgress>,
+) {
+    let mut loaded = 0;
+    let mut failed = 0;
+    for handle in &loading_progress.handles {
+        // Assets that are not managed by the asset server (such as assets added to `Assets` directly) are already loaded.
+        match asset_server.get_recursive_dependency_load_state(handle.id()) {
+            None | Some(RecursiveDependencyLoadState::Loaded) => loaded += 1,
+            Some(RecursiveDependencyLoadState::Failed(_)) => failed += 1,
+            Some(_) => {}
+        }
+    }
+    if loading_progress.loaded != loaded || loading_progress.failed != failed {
+        loading_progress.loaded = loaded;
+        loading_progress.failed = failed;
+    }
+}
+
+/// Returns a system that sets [`NextState`] to `next` once [`LoadingProgress::is_complete`] returns `true`.
+///
+/// This is typically run only while in the loading state:
+///
+/// ```
+/// # use bevy_app::{App, Update};
+/// # use bevy_asset::transition_when_loaded;
+/// # use bevy_ecs::schedule::IntoSystemConfigs;
+/// # use bevy_state::prelude::*;
+/// #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+/// enum GameState {
+///     #[default]
+///     Loading,
+///     Playing,
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_systems(
+///     Update,
+///     transition_when_loaded(GameState::Playing).run_if(in_state(GameState::Loading)),
+/// );
+/// ```
+#[cfg(feature = "bevy_state")]
+pub fn transition_when_loaded<S: FreelyMutableState>(
+    next: S,
+) -> impl FnMut(Res<LoadingProgress>, ResMut<NextState<S>>) {
+    move |loading_progress, mut next_state| {
+        if loading_progress.is_complete() {
+            next_state.set(next.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoadingProgress, Progress};
+    use crate::{Asset, AssetApp, AssetPlugin, Assets};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_reflect::TypePath;
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset;
+
+    #[test]
+    fn aggregate_progress() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<TestAsset>();
+
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<TestAsset>>()
+            .add(TestAsset);
+        let mut loading_progress = app.world_mut().resource_mut::<LoadingProgress>();
+        assert!(loading_progress.is_complete());
+        loading_progress.track(handle);
+        loading_progress.set_custom("shaders", Progress::new(1, 4));
+        assert!(!loading_progress.is_complete());
+        assert_eq!(loading_progress.fraction(), 0.125);
+
+        app.update();
+        let mut loading_progress = app.world_mut().resource_mut::<LoadingProgress>();
+        assert_eq!(loading_progress.assets(), Progress::new(1, 1));
+        assert_eq!(loading_progress.percent(), 62.5);
+
+        loading_progress.set_custom("shaders", Progress::new(4, 4));
+        assert!(loading_progress.is_complete());
+
+        loading_progress.clear();
+        assert_eq!(loading_progress.assets(), Progress::new(0, 0));
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use atomicow::CowArc;
-use bevy_platform_support::collections::HashMap;
+use bevy_platform_support::{collections::HashMap, hash::FixedHasher};
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::{borrow::Borrow, hash::Hash, ops::Deref};
 use serde::{Deserialize, Serialize};
@@ -99,6 +99,17 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         })
     }
 
+    /// Creates a new [`SavedAsset`] from a standalone `asset` without any labeled assets, such as an asset that was
+    /// generated at runtime.
+    pub fn from_asset(value: &'a A) -> Self {
+        static NO_LABELED_ASSETS: HashMap<CowArc<'static, str>, LabeledAsset> =
+            HashMap::with_hasher(FixedHasher);
+        Self {
+            value,
+            labeled_assets: &NO_LABELED_ASSETS,
+        }
+    }
+
     /// Creates a new [`SavedAsset`] from the a [`TransformedAsset`]
     pub fn from_transformed(asset: &'a TransformedAsset<A>) -> Self {
         Self {
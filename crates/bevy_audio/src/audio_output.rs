@@ -5,6 +5,7 @@ use crate::{
 use bevy_asset::{Asset, Assets};
 use bevy_ecs::{prelude::*, system::SystemParam};
 use bevy_math::Vec3;
+use bevy_time::DomainTime;
 use bevy_transform::prelude::GlobalTransform;
 use rodio::{OutputStream, OutputStreamHandle, Sink, Source, SpatialSink};
 use tracing::warn;
@@ -282,6 +283,83 @@ pub(crate) fn cleanup_finished_audio<T: Decodable + Asset>(
     }
 }
 
+/// The [`TimeDomain`](bevy_time::TimeDomain) playback state of an audio sink, for internal use by
+/// [`update_time_domain_playback`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub(crate) struct TimeDomainPlayback {
+    /// The last non-zero speed of the time domain, which the speed of the sink is scaled by.
+    speed: f32,
+    /// Whether the sink was paused because its time domain was.
+    paused: bool,
+}
+
+impl Default for TimeDomainPlayback {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            paused: false,
+        }
+    }
+}
+
+impl TimeDomainPlayback {
+    /// Applies the `speed` of the time domain to `sink`, pausing it when the speed is zero.
+    ///
+    /// Sinks which were already paused stay paused when the time domain resumes, and changes to
+    /// the speed of the sink made outside of the time domain are kept.
+    fn apply(&mut self, sink: &impl AudioSinkPlayback, speed: f32) {
+        if speed == 0.0 {
+            if !self.paused && !sink.is_paused() {
+                sink.pause();
+                self.paused = true;
+            }
+            return;
+        }
+        if self.paused {
+            sink.play();
+            self.paused = false;
+        }
+        if speed != self.speed {
+            sink.set_speed(sink.speed() / self.speed * speed);
+            self.speed = speed;
+        }
+    }
+}
+
+/// Scales the speed of audio sinks by the [`TimeDomain`](bevy_time::TimeDomain) of their entity,
+/// and pauses them while it is paused.
+pub(crate) fn update_time_domain_playback(
+    mut commands: Commands,
+    time: DomainTime,
+    mut sinks: Query<(
+        Entity,
+        AnyOf<(&AudioSink, &SpatialAudioSink)>,
+        Option<&mut TimeDomainPlayback>,
+    )>,
+) {
+    for (entity, (sink, spatial_sink), playback) in &mut sinks {
+        let speed = time.speed(entity) as f32;
+        if playback.is_none() && speed == 1.0 {
+            continue;
+        }
+
+        let mut state = playback.as_deref().copied().unwrap_or_default();
+        match (sink, spatial_sink) {
+            (Some(sink), _) => state.apply(sink, speed),
+            (_, Some(sink)) => state.apply(sink, speed),
+            (None, None) => continue,
+        }
+        match playback {
+            Some(mut playback) => {
+                playback.set_if_neq(state);
+            }
+            None => {
+                commands.entity(entity).insert(state);
+            }
+        }
+    }
+}
+
 /// Run Condition to only play audio if the audio output is available
 pub(crate) fn audio_output_available(audio_output: Res<AudioOutput>) -> bool {
     audio_output.stream_handle.is_some()
@@ -332,3 +410,56 @@ pub(crate) fn update_listener_positions(
         sink.set_ears_position(left_ear * scale, right_ear * scale);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{update_time_domain_playback, TimeDomainPlayback};
+    use crate::{AudioSink, AudioSinkPlayback};
+    use bevy_ecs::{hierarchy::ChildOf, system::RunSystemOnce, world::World};
+    use bevy_time::{Time, TimeDomain};
+    use rodio::Sink;
+
+    #[test]
+    fn time_domain_playback() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let domain = world.spawn(TimeDomain::from_relative_speed(0.5)).id();
+        let (sink, _queue_rx) = Sink::new_idle();
+        let audio = world.spawn((ChildOf(domain), AudioSink::new(sink))).id();
+        let (sink, _queue_rx) = Sink::new_idle();
+        let outside = world.spawn(AudioSink::new(sink)).id();
+
+        let mut update = |world: &mut World| {
+            world.run_system_once(update_time_domain_playback).unwrap();
+        };
+        update(&mut world);
+        assert_eq!(0.5, world.get::<AudioSink>(audio).unwrap().speed());
+        assert_eq!(1.0, world.get::<AudioSink>(outside).unwrap().speed());
+        assert!(!world.entity(outside).contains::<TimeDomainPlayback>());
+
+        // Speed changes of the sink itself are kept when the domain speed changes
+        world.get::<AudioSink>(audio).unwrap().set_speed(1.0);
+        world
+            .get_mut::<TimeDomain>(domain)
+            .unwrap()
+            .set_relative_speed(4.0);
+        update(&mut world);
+        assert_eq!(8.0, world.get::<AudioSink>(audio).unwrap().speed());
+
+        world.get_mut::<TimeDomain>(domain).unwrap().pause();
+        update(&mut world);
+        assert!(world.get::<AudioSink>(audio).unwrap().is_paused());
+        world.get_mut::<TimeDomain>(domain).unwrap().unpause();
+        update(&mut world);
+        assert!(!world.get::<AudioSink>(audio).unwrap().is_paused());
+
+        // Sinks paused on their own stay paused
+        world.get::<AudioSink>(audio).unwrap().pause();
+        world.get_mut::<TimeDomain>(domain).unwrap().pause();
+        update(&mut world);
+        world.get_mut::<TimeDomain>(domain).unwrap().unpause();
+        update(&mut world);
+        assert!(world.get::<AudioSink>(audio).unwrap().is_paused());
+        assert_eq!(8.0, world.get::<AudioSink>(audio).unwrap().speed());
+    }
+}
@@ -1,5 +1,9 @@
 use alloc::sync::Arc;
-use bevy_asset::{io::Reader, Asset, AssetLoader, LoadContext};
+use bevy_asset::{
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    Asset, AssetLoader, AsyncWriteExt, LoadContext,
+};
 use bevy_reflect::TypePath;
 use std::io::Cursor;
 
@@ -73,6 +77,29 @@ impl AssetLoader for AudioLoader {
     }
 }
 
+/// Saves [`AudioSource`] [`Assets`](bevy_asset::Assets) by writing their raw [`bytes`](AudioSource::bytes).
+///
+/// The audio data is written as-is, so the saved file should use the extension of the original encoded format
+/// (such as `.ogg` or `.wav`) to be loaded back with the [`AudioLoader`].
+#[derive(Default)]
+pub struct AudioSaver;
+
+impl AssetSaver for AudioSaver {
+    type Asset = AudioSource;
+    type Settings = ();
+    type OutputLoader = AudioLoader;
+    type Error = std::io::Error;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        writer.write_all(&asset.bytes).await
+    }
+}
+
 /// A type implementing this trait can be converted to a [`rodio::Source`] type.
 ///
 /// It must be [`Send`] and [`Sync`] in order to be registered.
@@ -117,3 +144,30 @@ pub trait AddAudioSource {
         T: Decodable + Asset,
         f32: rodio::cpal::FromSample<T::DecoderItem>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AudioSaver, AudioSource};
+    use bevy_asset::saver::{AssetSaver, SavedAsset};
+
+    #[test]
+    fn audio_saver_round_trip() {
+        // The saver does not decode the audio, so any bytes will do
+        let source = AudioSource {
+            bytes: b"RIFF\x24\x00\x00\x00WAVEfmt "[..].into(),
+        };
+
+        let mut bytes = Vec::new();
+        futures_lite::future::block_on(AudioSaver.save(
+            &mut bytes,
+            SavedAsset::from_asset(&source),
+            &(),
+        ))
+        .unwrap();
+
+        let loaded = AudioSource {
+            bytes: bytes.into(),
+        };
+        assert_eq!(loaded.as_ref(), source.as_ref());
+    }
+}
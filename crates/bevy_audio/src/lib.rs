@@ -58,6 +58,7 @@ pub use sinks::*;
 use bevy_app::prelude::*;
 use bevy_asset::{Asset, AssetApp};
 use bevy_ecs::prelude::*;
+use bevy_time::Time;
 use bevy_transform::TransformSystem;
 
 use audio_output::*;
@@ -69,6 +70,9 @@ struct AudioPlaySet;
 /// Adds support for audio playback to a Bevy Application
 ///
 /// Insert an [`AudioPlayer`] onto your entities to play audio.
+///
+/// Audio entities inside of a [`TimeDomain`](bevy_time::TimeDomain) play at the speed of the domain,
+/// and are paused with it. The speed of [`Time<Virtual>`](bevy_time::Virtual) does not affect audio.
 #[derive(Default)]
 pub struct AudioPlugin {
     /// The global volume for all audio entities.
@@ -96,7 +100,12 @@ impl Plugin for AudioPlugin {
             )
             .add_systems(
                 PostUpdate,
-                (update_emitter_positions, update_listener_positions).in_set(AudioPlaySet),
+                (
+                    update_emitter_positions,
+                    update_listener_positions,
+                    update_time_domain_playback.run_if(resource_exists::<Time>),
+                )
+                    .in_set(AudioPlaySet),
             )
             .init_resource::<AudioOutput>();
 
@@ -0,0 +1,383 @@
+//! Plugins to record the input of an app to a file and to replay it, for reproducing bugs and regression testing.
+//!
+//! The [`InputRecordingPlugin`] records the keyboard, mouse, cursor, gamepad and touch events received on every
+//! frame, alongside the duration of the frame. The [`InputReplayPlugin`] feeds a recording back to an app,
+//! frame by frame, and drives [`Time`] with the recorded frame durations through
+//! [`TimeUpdateStrategy::ManualDuration`]. This makes it possible to replay a session headlessly.
+//!
+//! Replayed events keep the window entities they were recorded with. Replaying [`CursorMoved`] does not move the
+//! cursor of the window, so [`Window::cursor_position`](bevy_window::Window::cursor_position) is not updated.
+
+use std::{fs, io, path::PathBuf};
+
+use bevy_app::{prelude::*, AppExit};
+use bevy_ecs::{entity::hash_map::EntityHashMap, event::EventUpdates, prelude::*};
+use bevy_input::{
+    gamepad::{GamepadConnectionEvent, RawGamepadEvent},
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    touch::TouchInput,
+};
+use bevy_time::{Real, Time, TimeSystem, TimeUpdateStrategy};
+use bevy_window::CursorMoved;
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, info};
+
+/// An input event recorded by the [`InputRecordingPlugin`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedInput {
+    /// A [`KeyboardInput`] event.
+    Keyboard(KeyboardInput),
+    /// A [`MouseButtonInput`] event.
+    MouseButton(MouseButtonInput),
+    /// A [`MouseMotion`] event.
+    MouseMotion(MouseMotion),
+    /// A [`MouseWheel`] event.
+    MouseWheel(MouseWheel),
+    /// A [`CursorMoved`] event.
+    CursorMoved(CursorMoved),
+    /// A [`RawGamepadEvent`].
+    Gamepad(RawGamepadEvent),
+    /// A [`TouchInput`] event.
+    Touch(TouchInput),
+}
+
+/// The input events received during a single frame, in the order they were received for each kind of event.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    /// The duration of the frame, as measured by [`Time<Real>`].
+    pub delta: Duration,
+    /// The input events received during the frame.
+    pub inputs: Vec<RecordedInput>,
+}
+
+/// A sequence of [`RecordedFrame`]s.
+///
+/// The [`InputRecordingPlugin`] fills this resource while the app runs.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputRecording {
+    /// The recorded frames, starting from the first update of the app.
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl InputRecording {
+    /// Saves the recording to `path` as [`ron`].
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), InputRecordingError> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Loads a recording previously saved with [`InputRecording::save`].
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, InputRecordingError> {
+        let content = fs::read_to_string(path)?;
+        Ok(ron::from_str(&content)?)
+    }
+}
+
+/// An error that occurs when saving or loading an [`InputRecording`].
+#[derive(Error, Debug)]
+pub enum InputRecordingError {
+    /// An [IO](std::io) error.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// A [RON](ron) serialization error.
+    #[error(transparent)]
+    Serialize(#[from] ron::Error),
+    /// A [RON](ron) deserialization error.
+    #[error(transparent)]
+    Deserialize(#[from] ron::error::SpannedError),
+}
+
+/// A plugin that records the input of the app into the [`InputRecording`] resource, and saves it to
+/// [`InputRecordingPlugin::path`] when an [`AppExit`] event is sent.
+///
+/// Recording happens in [`Last`], so [`AppExit`] events sent later during the last frame are not seen.
+pub struct InputRecordingPlugin {
+    /// The file the recording is saved to.
+    pub path: PathBuf,
+}
+
+impl InputRecordingPlugin {
+    /// Creates an [`InputRecordingPlugin`] saving to `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        let path = self.path.clone();
+        add_input_events(app);
+        app.init_resource::<InputRecording>().add_systems(
+            Last,
+            (
+                record_inputs,
+                (move |recording: Res<InputRecording>| match recording.save(&path) {
+                    Ok(()) => info!("Saved input recording to {}.", path.display()),
+                    Err(err) => error!(
+                        "Failed to save input recording to {}: {err}",
+                        path.display()
+                    ),
+                })
+                .run_if(on_event::<AppExit>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Records the input events of the current frame into the [`InputRecording`].
+pub fn record_inputs(
+    mut recording: ResMut<InputRecording>,
+    time: Res<Time<Real>>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse_button: EventReader<MouseButtonInput>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut gamepad: EventReader<RawGamepadEvent>,
+    mut touch: EventReader<TouchInput>,
+) {
+    let inputs = keyboard
+        .read()
+        .cloned()
+        .map(RecordedInput::Keyboard)
+        .chain(mouse_button.read().cloned().map(RecordedInput::MouseButton))
+        .chain(mouse_motion.read().cloned().map(RecordedInput::MouseMotion))
+        .chain(mouse_wheel.read().cloned().map(RecordedInput::MouseWheel))
+        .chain(cursor_moved.read().cloned().map(RecordedInput::CursorMoved))
+        .chain(gamepad.read().cloned().map(RecordedInput::Gamepad))
+        .chain(touch.read().cloned().map(RecordedInput::Touch))
+        .collect();
+    recording.frames.push(RecordedFrame {
+        delta: time.delta(),
+        inputs,
+    });
+}
+
+/// A plugin that replays an [`InputRecording`], sending its events and setting [`TimeUpdateStrategy::ManualDuration`]
+/// to its frame durations.
+///
+/// Events are sent in [`First`], before [`Time`] is updated, so they are visible to the [`InputSystem`] of the same
+/// frame. Recorded gamepads are spawned as new entities when their connection is replayed.
+///
+/// [`InputSystem`]: bevy_input::InputSystem
+pub struct InputReplayPlugin {
+    /// The recording to replay.
+    pub recording: InputRecording,
+    /// Whether to send [`AppExit::Success`] once every frame has been replayed.
+    pub exit_when_finished: bool,
+}
+
+impl InputReplayPlugin {
+    /// Creates an [`InputReplayPlugin`] replaying `recording`, exiting the app once it is finished.
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            exit_when_finished: true,
+        }
+    }
+
+    /// Creates an [`InputReplayPlugin`] replaying the recording saved at `path`, exiting the app once it is finished.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, InputRecordingError> {
+        InputRecording::load(path).map(Self::new)
+    }
+
+    /// Sets whether to send [`AppExit::Success`] once every frame has been replayed.
+    #[must_use]
+    pub fn with_exit_when_finished(mut self, exit_when_finished: bool) -> Self {
+        self.exit_when_finished = exit_when_finished;
+        self
+    }
+}
+
+impl Plugin for InputReplayPlugin {
+    fn build(&self, app: &mut App) {
+        add_input_events(app);
+        app.insert_resource(InputReplay {
+            recording: self.recording.clone(),
+            exit_when_finished: self.exit_when_finished,
+            frame: 0,
+            gamepads: EntityHashMap::default(),
+        })
+        .add_systems(First, replay_inputs.after(EventUpdates).before(TimeSystem));
+    }
+}
+
+/// The state of the replay of the [`InputReplayPlugin`].
+#[derive(Resource, Debug)]
+pub struct InputReplay {
+    recording: InputRecording,
+    exit_when_finished: bool,
+    frame: usize,
+    gamepads: EntityHashMap<Entity>,
+}
+
+impl InputReplay {
+    /// Returns the index of the next frame to replay.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns `true` if every frame of the recording has been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.recording.frames.len()
+    }
+}
+
+/// Sends the recorded input events of the current frame and sets its duration.
+pub fn replay_inputs(world: &mut World) {
+    let mut replay = world.resource_mut::<InputReplay>();
+    let Some(frame) = replay.recording.frames.get(replay.frame).cloned() else {
+        if replay.exit_when_finished {
+            replay.exit_when_finished = false;
+            info!("Finished replaying {} frames.", replay.frame);
+            world.send_event(AppExit::Success);
+        }
+        return;
+    };
+    replay.frame += 1;
+
+    world.insert_resource(TimeUpdateStrategy::ManualDuration(frame.delta));
+    for input in frame.inputs {
+        match input {
+            RecordedInput::Keyboard(event) => {
+                world.send_event(event);
+            }
+            RecordedInput::MouseButton(event) => {
+                world.send_event(event);
+            }
+            RecordedInput::MouseMotion(event) => {
+                world.send_event(event);
+            }
+            RecordedInput::MouseWheel(event) => {
+                world.send_event(event);
+            }
+            RecordedInput::CursorMoved(event) => {
+                world.send_event(event);
+            }
+            RecordedInput::Gamepad(mut event) => {
+                let recorded = match &event {
+                    RawGamepadEvent::Connection(event) => event.gamepad,
+                    RawGamepadEvent::Button(event) => event.gamepad,
+                    RawGamepadEvent::Axis(event) => event.gamepad,
+                };
+                let gamepad = match world.resource::<InputReplay>().gamepads.get(&recorded) {
+                    Some(&gamepad) => gamepad,
+                    None => {
+                        let gamepad = world.spawn_empty().id();
+                        world
+                            .resource_mut::<InputReplay>()
+                            .gamepads
+                            .insert(recorded, gamepad);
+                        gamepad
+                    }
+                };
+                match &mut event {
+                    RawGamepadEvent::Connection(GamepadConnectionEvent {
+                        gamepad: entity, ..
+                    }) => *entity = gamepad,
+                    RawGamepadEvent::Button(event) => event.gamepad = gamepad,
+                    RawGamepadEvent::Axis(event) => event.gamepad = gamepad,
+                }
+                world.send_event(event);
+            }
+            RecordedInput::Touch(event) => {
+                world.send_event(event);
+            }
+        }
+    }
+}
+
+fn add_input_events(app: &mut App) {
+    app.add_event::<KeyboardInput>()
+        .add_event::<MouseButtonInput>()
+        .add_event::<MouseMotion>()
+        .add_event::<MouseWheel>()
+        .add_event::<CursorMoved>()
+        .add_event::<RawGamepadEvent>()
+        .add_event::<TouchInput>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputRecording, InputRecordingPlugin, InputReplay, InputReplayPlugin};
+    use bevy_app::App;
+    use bevy_ecs::entity::Entity;
+    use bevy_input::{
+        keyboard::{Key, KeyCode, KeyboardInput},
+        ButtonInput, ButtonState, InputPlugin,
+    };
+    use bevy_time::{Real, Time, TimePlugin, TimeUpdateStrategy};
+    use core::time::Duration;
+
+    fn key(key_code: KeyCode, state: ButtonState) -> KeyboardInput {
+        KeyboardInput {
+            key_code,
+            logical_key: Key::Space,
+            state,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            InputPlugin,
+            InputRecordingPlugin::new("unused.ron"),
+        ));
+        let mut pressed = Vec::new();
+        for frame in 0..4u64 {
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                10 * frame,
+            )));
+            match frame {
+                1 => {
+                    _ = app
+                        .world_mut()
+                        .send_event(key(KeyCode::Space, ButtonState::Pressed))
+                }
+                3 => {
+                    _ = app
+                        .world_mut()
+                        .send_event(key(KeyCode::Space, ButtonState::Released))
+                }
+                _ => {}
+            }
+            app.update();
+            pressed.push(
+                app.world()
+                    .resource::<ButtonInput<KeyCode>>()
+                    .pressed(KeyCode::Space),
+            );
+        }
+        let recording = app.world().resource::<InputRecording>().clone();
+        let elapsed = app.world().resource::<Time<Real>>().elapsed();
+
+        let mut replay = App::new();
+        replay.add_plugins((
+            TimePlugin,
+            InputPlugin,
+            InputReplayPlugin::new(recording).with_exit_when_finished(false),
+        ));
+        for expected in pressed {
+            replay.update();
+            assert_eq!(
+                replay
+                    .world()
+                    .resource::<ButtonInput<KeyCode>>()
+                    .pressed(KeyCode::Space),
+                expected
+            );
+        }
+        assert!(replay.world().resource::<InputReplay>().is_finished());
+        assert_eq!(replay.world().resource::<Time<Real>>().elapsed(), elapsed);
+    }
+}
@@ -15,6 +15,9 @@ pub mod ci_testing;
 
 pub mod fps_overlay;
 
+#[cfg(feature = "input_recording")]
+pub mod input_recording;
+
 pub mod picking_debug;
 
 pub mod states;
@@ -71,6 +71,11 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(err) => err.into_compile_error().into(),
     };
 
+    let track_fields = match derive_track_fields(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let visit_entities = visit_entities(&ast.data, &bevy_ecs_path, relationship.is_some());
 
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
@@ -199,6 +204,17 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             }
         }
     }
+    if track_fields.is_some() {
+        register_required.push(quote! {
+            components.register_required_components_manual::<Self, #bevy_ecs_path::field_change_detection::FieldTicks<Self>>(
+                required_components,
+                <#bevy_ecs_path::field_change_detection::FieldTicks<Self> as Default>::default,
+                inheritance_depth,
+                recursion_check_stack
+            );
+        });
+    }
+
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
@@ -252,9 +268,90 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         #relationship
 
         #relationship_target
+
+        #track_fields
     })
 }
 
+fn derive_track_fields(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(module) = &attrs.track_fields else {
+        return Ok(None);
+    };
+    const TRACK_FIELDS_FORMAT_MESSAGE: &str =
+        "`track_fields` is only supported on non-generic structs with named fields";
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new(ast.span(), TRACK_FIELDS_FORMAT_MESSAGE));
+    }
+    let Data::Struct(DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = &ast.data
+    else {
+        return Err(syn::Error::new(ast.span(), TRACK_FIELDS_FORMAT_MESSAGE));
+    };
+
+    let struct_name = &ast.ident;
+    let vis = &ast.vis;
+    // The markers are declared in a child module, and must be visible wherever the struct is.
+    let marker_vis = match vis {
+        Visibility::Inherited => quote!(pub(super)),
+        Visibility::Restricted(restricted)
+            if restricted.path.is_ident("self") || restricted.path.is_ident("super") =>
+        {
+            let path = &restricted.path;
+            quote!(pub(in super::#path))
+        }
+        vis => vis.to_token_stream(),
+    };
+    let names = fields
+        .named
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap().to_string());
+    let markers = fields.named.iter().enumerate().map(|(index, field)| {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let doc = format!("The `{ident}` field of [`{struct_name}`].");
+        quote! {
+            #[doc = #doc]
+            #marker_vis struct #ident;
+
+            impl #bevy_ecs_path::field_change_detection::ComponentField for #ident {
+                type Component = #struct_name;
+                type Type = #ty;
+                const INDEX: usize = #index;
+
+                fn get(component: &#struct_name) -> &#ty {
+                    &component.#ident
+                }
+
+                fn get_mut(component: &mut #struct_name) -> &mut #ty {
+                    &mut component.#ident
+                }
+            }
+        }
+    });
+    let doc = format!("The fields of [`{struct_name}`], for per-field change detection.");
+
+    Ok(Some(quote! {
+        impl #bevy_ecs_path::field_change_detection::TrackedFields for #struct_name {
+            const FIELDS: &'static [&'static str] = &[#(#names),*];
+        }
+
+        #[doc = #doc]
+        #[allow(non_camel_case_types)]
+        #vis mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#markers)*
+        }
+    }))
+}
+
 fn visit_entities(data: &Data, bevy_ecs_path: &Path, is_relationship: bool) -> TokenStream2 {
     match data {
         Data::Struct(DataStruct { ref fields, .. }) => {
@@ -435,6 +532,8 @@ pub const ON_DESPAWN: &str = "on_despawn";
 
 pub const IMMUTABLE: &str = "immutable";
 
+pub const TRACK_FIELDS: &str = "track_fields";
+
 struct Attrs {
     storage: StorageTy,
     requires: Option<Punctuated<Require, Comma>>,
@@ -446,6 +545,7 @@ struct Attrs {
     relationship: Option<Relationship>,
     relationship_target: Option<RelationshipTarget>,
     immutable: bool,
+    track_fields: Option<Ident>,
 }
 
 #[derive(Clone, Copy)]
@@ -489,6 +589,7 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         relationship: None,
         relationship_target: None,
         immutable: false,
+        track_fields: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -524,6 +625,9 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                 } else if nested.path.is_ident(IMMUTABLE) {
                     attrs.immutable = true;
                     Ok(())
+                } else if nested.path.is_ident(TRACK_FIELDS) {
+                    attrs.track_fields = Some(nested.value()?.parse::<Ident>()?);
+                    Ok(())
                 } else {
                     Err(nested.error("Unsupported attribute"))
                 }
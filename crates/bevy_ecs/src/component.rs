@@ -320,6 +320,16 @@ pub use bevy_ecs_macros::require;
 /// }
 /// ```
 ///
+/// # Tracking changes of individual fields
+///
+/// The `#[component(track_fields = module_name)]` attribute gives each field of the component
+/// its own change ticks, and generates a module named `module_name` with a marker type for each
+/// field, to use with [`ChangedField`] and [`FieldsMut`].
+/// See the [`field_change_detection`](crate::field_change_detection) module for more info.
+///
+/// [`ChangedField`]: crate::field_change_detection::ChangedField
+/// [`FieldsMut`]: crate::field_change_detection::FieldsMut
+///
 /// # Implementing the trait for foreign types
 ///
 /// As a consequence of the [orphan rule], it is not possible to separate into two different crates the implementation of `Component` from the definition of a type.
@@ -1002,6 +1012,27 @@ impl ComponentDescriptor {
         }
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    /// Create a new `ComponentDescriptor` holding values of the type `T`, which does not need
+    /// to be a [`Component`]. Unlike [`ComponentDescriptor::new`], each descriptor registers a
+    /// different component, which is used for components created at runtime.
+    pub(crate) fn new_runtime<T: Send + Sync + 'static>(
+        name: impl Into<Cow<'static, str>>,
+        storage_type: StorageType,
+        clone_behavior: ComponentCloneBehavior,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            storage_type,
+            is_send_and_sync: true,
+            type_id: Some(TypeId::of::<T>()),
+            layout: Layout::new::<T>(),
+            drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            mutable: true,
+            clone_behavior,
+        }
+    }
+
     /// Create a new `ComponentDescriptor`.
     ///
     /// # Safety
@@ -3,7 +3,8 @@
 //! Entities which are disabled in this way are not removed from the [`World`],
 //! and their relationships remain intact.
 //! In many cases, you may want to disable entire trees of entities at once,
-//! using [`EntityCommands::insert_recursive`](crate::prelude::EntityCommands::insert_recursive).
+//! using [`EntityCommands::disable_recursive`](crate::prelude::EntityCommands::disable_recursive),
+//! which can be undone with [`EntityCommands::enable_recursive`](crate::prelude::EntityCommands::enable_recursive).
 //!
 //! While Bevy ships with a built-in [`Disabled`] component, you can also create your own
 //! disabling components, which will operate in the same way but can have distinct semantics.
@@ -34,6 +35,36 @@
 //! assert_eq!(3, maybe_prefab_query.iter(&world).count());
 //! ```
 //!
+//! ## Recursive disabling
+//!
+//! [`EntityWorldMut::disable_recursive`] inserts a disabling component on an entity and all of its descendants,
+//! and remembers which of them did not have it before with a [`DisabledRecursively`] marker pointing to the root of the tree.
+//! [`EntityWorldMut::enable_recursive`] then only removes the disabling component from the entities disabled from the same root,
+//! so that descendants which were disabled on their own, or as part of a subtree, stay disabled.
+//! This is useful for object pools and prefab templates, whose trees are disabled and enabled as a whole.
+//!
+//! ```
+//! use bevy_ecs::prelude::*;
+//!
+//! #[derive(Component, Clone)]
+//! struct Pooled;
+//!
+//! let mut world = World::new();
+//! world.register_disabling_component::<Pooled>();
+//!
+//! let bullet = world.spawn_empty().id();
+//! let trail = world.spawn((ChildOf(bullet), Pooled)).id();
+//! let sparks = world.spawn(ChildOf(bullet)).id();
+//!
+//! world.entity_mut(bullet).disable_recursive::<Children>(Pooled);
+//! assert_eq!(0, world.query::<Entity>().iter(&world).count());
+//!
+//! world.entity_mut(bullet).enable_recursive::<Children, Pooled>();
+//! // The trail was pooled before the bullet, and stays disabled.
+//! assert!(world.entity(trail).contains::<Pooled>());
+//! assert!(!world.entity(sparks).contains::<Pooled>());
+//! ```
+//!
 //! ## Default query filters
 //!
 //! In Bevy, entity disabling is implemented through the construction of a global "default query filter".
@@ -68,11 +99,16 @@
 //! [`Query` performance]: crate::prelude::Query#performance
 
 use crate::{
-    component::{ComponentId, Components, StorageType},
+    component::{Component, ComponentId, Components, StorageType},
+    entity::Entity,
     query::FilteredAccess,
-    world::{FromWorld, World},
+    relationship::RelationshipTarget,
+    system::EntityCommands,
+    world::{EntityWorldMut, FromWorld, World},
 };
-use bevy_ecs_macros::{Component, Resource};
+use alloc::vec::Vec;
+use bevy_ecs_macros::Resource;
+use core::marker::PhantomData;
 use smallvec::SmallVec;
 
 #[cfg(feature = "bevy_reflect")]
@@ -85,7 +121,7 @@ use {crate::reflect::ReflectComponent, bevy_reflect::Reflect};
 ///
 /// Like all disabling components, this only disables the entity itself,
 /// not its children or other entities that reference it.
-/// To disable an entire tree of entities, use [`EntityCommands::insert_recursive`](crate::prelude::EntityCommands::insert_recursive).
+/// To disable an entire tree of entities, use [`EntityCommands::disable_recursive`].
 ///
 /// Every [`World`] has a default query filter that excludes entities with this component,
 /// registered in the [`DefaultQueryFilters`] resource.
@@ -197,13 +233,153 @@ impl DefaultQueryFilters {
     }
 }
 
+/// A marker for entities whose disabling component `C` was inserted by [`EntityWorldMut::disable_recursive`],
+/// which [`EntityWorldMut::enable_recursive`] removes along with `C` when called on the same root entity.
+///
+/// Entities which already had `C` are not marked, and stay disabled when the tree is enabled again.
+#[derive(Component)]
+pub struct DisabledRecursively<C: Component> {
+    root: Entity,
+    _marker: PhantomData<C>,
+}
+
+impl<C: Component> DisabledRecursively<C> {
+    fn new(root: Entity) -> Self {
+        Self {
+            root,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the entity [`disable_recursive`](EntityWorldMut::disable_recursive) was called on.
+    pub fn root(&self) -> Entity {
+        self.root
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Inserts the disabling component `component` on this entity and all related entities,
+    /// traversing the relationship tracked in `S` in a breadth-first manner.
+    ///
+    /// The entities which did not already have the component are marked with [`DisabledRecursively`],
+    /// so that [`enable_recursive`](Self::enable_recursive) on this entity only enables them again.
+    /// Subtrees that were already disabled recursively keep their own root, and are not enabled
+    /// along with this entity.
+    ///
+    /// # Warning
+    ///
+    /// This method should only be called on relationships that form a tree-like structure.
+    /// Any cycles will cause this method to loop infinitely.
+    pub fn disable_recursive<S: RelationshipTarget>(
+        &mut self,
+        component: impl Component + Clone,
+    ) -> &mut Self {
+        fn disable<C: Component + Clone>(
+            world: &mut World,
+            root: Entity,
+            entities: Vec<Entity>,
+            component: C,
+        ) {
+            for entity in entities {
+                let mut entity = world.entity_mut(entity);
+                if !entity.contains::<C>() {
+                    entity.insert((component.clone(), DisabledRecursively::<C>::new(root)));
+                }
+            }
+        }
+
+        let root = self.id();
+        let mut entities = Vec::new();
+        self.collect_related_recursive::<S>(&mut entities);
+        self.world_scope(|world| disable(world, root, entities, component));
+        self
+    }
+
+    /// Removes the disabling component `C` from this entity and all related entities,
+    /// traversing the relationship tracked in `S` in a breadth-first manner,
+    /// if it was inserted by [`disable_recursive`](Self::disable_recursive) on this entity.
+    ///
+    /// # Warning
+    ///
+    /// This method should only be called on relationships that form a tree-like structure.
+    /// Any cycles will cause this method to loop infinitely.
+    pub fn enable_recursive<S: RelationshipTarget, C: Component>(&mut self) -> &mut Self {
+        let root = self.id();
+        let mut entities = Vec::new();
+        self.collect_related_recursive::<S>(&mut entities);
+        self.world_scope(|world| {
+            for entity in entities {
+                let mut entity = world.entity_mut(entity);
+                if entity
+                    .get::<DisabledRecursively<C>>()
+                    .is_some_and(|disabled| disabled.root == root)
+                {
+                    entity.remove::<(C, DisabledRecursively<C>)>();
+                }
+            }
+        });
+        self
+    }
+
+    fn collect_related_recursive<S: RelationshipTarget>(&self, entities: &mut Vec<Entity>) {
+        entities.push(self.id());
+        let mut index = entities.len() - 1;
+        while index < entities.len() {
+            if let Some(relationship_target) = self.world().get::<S>(entities[index]) {
+                entities.extend(relationship_target.iter());
+            }
+            index += 1;
+        }
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Inserts the disabling component `component` on this entity and all related entities,
+    /// traversing the relationship tracked in `S` in a breadth-first manner.
+    /// See [`EntityWorldMut::disable_recursive`].
+    ///
+    /// # Warning
+    ///
+    /// This method should only be called on relationships that form a tree-like structure.
+    /// Any cycles will cause this method to loop infinitely.
+    pub fn disable_recursive<S: RelationshipTarget>(
+        &mut self,
+        component: impl Component + Clone,
+    ) -> &mut Self {
+        let id = self.id();
+        self.commands().queue(move |world: &mut World| {
+            world.entity_mut(id).disable_recursive::<S>(component);
+        });
+        self
+    }
+
+    /// Removes the disabling component `C` from this entity and all related entities,
+    /// traversing the relationship tracked in `S` in a breadth-first manner,
+    /// if it was inserted by [`disable_recursive`](Self::disable_recursive) on this entity.
+    /// See [`EntityWorldMut::enable_recursive`].
+    ///
+    /// # Warning
+    ///
+    /// This method should only be called on relationships that form a tree-like structure.
+    /// Any cycles will cause this method to loop infinitely.
+    pub fn enable_recursive<S: RelationshipTarget, C: Component>(&mut self) -> &mut Self {
+        let id = self.id();
+        self.commands().queue(move |world: &mut World| {
+            world.entity_mut(id).enable_recursive::<S, C>();
+        });
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
     use crate::{
+        hierarchy::{ChildOf, Children},
         prelude::World,
         query::{Has, With},
+        system::{Commands, RunSystemOnce},
     };
     use alloc::{vec, vec::Vec};
 
@@ -301,4 +477,83 @@ mod tests {
         let mut query = world.query::<(Has<Disabled>, Has<CustomDisabled>)>();
         assert_eq!(4, query.iter(&world).count());
     }
+
+    #[derive(Component, Clone)]
+    struct Pooled;
+
+    #[test]
+    fn disable_and_enable_recursive() {
+        let mut world = World::new();
+        world.register_disabling_component::<Pooled>();
+
+        let a = world.spawn_empty().id();
+        let b = world.spawn(ChildOf(a)).id();
+        let c = world.spawn((ChildOf(a), Pooled)).id();
+        let d = world.spawn(ChildOf(c)).id();
+        let e = world.spawn((ChildOf(b), Disabled)).id();
+
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.entity(a).disable_recursive::<Children>(Pooled);
+            })
+            .unwrap();
+        for entity in [a, b, c, d, e] {
+            assert!(world.entity(entity).contains::<Pooled>());
+        }
+        assert!(!world.entity(c).contains::<DisabledRecursively<Pooled>>());
+        assert_eq!(0, world.query::<()>().iter(&world).count());
+
+        // Enabling with another disabling component does nothing
+        world.entity_mut(a).enable_recursive::<Children, Disabled>();
+        assert_eq!(0, world.query::<()>().iter(&world).count());
+
+        world.entity_mut(a).enable_recursive::<Children, Pooled>();
+        for entity in [a, b, d, e] {
+            assert!(!world.entity(entity).contains::<Pooled>());
+            assert!(!world
+                .entity(entity)
+                .contains::<DisabledRecursively<Pooled>>());
+        }
+        // `c` was disabled before its parent, and `e` with another component
+        assert!(world.entity(c).contains::<Pooled>());
+        assert!(world.entity(e).contains::<Disabled>());
+
+        let mut query = world.query::<Entity>();
+        let mut enabled = query.iter(&world).collect::<Vec<_>>();
+        enabled.sort();
+        let mut expected = vec![a, b, d];
+        expected.sort();
+        assert_eq!(expected, enabled);
+    }
+
+    #[test]
+    fn nested_disable_recursive() {
+        let mut world = World::new();
+        world.register_disabling_component::<Pooled>();
+
+        let a = world.spawn_empty().id();
+        let b = world.spawn(ChildOf(a)).id();
+        let c = world.spawn(ChildOf(b)).id();
+        let d = world.spawn(ChildOf(a)).id();
+
+        world.entity_mut(b).disable_recursive::<Children>(Pooled);
+        world.entity_mut(a).disable_recursive::<Children>(Pooled);
+        assert_eq!(0, world.query::<()>().iter(&world).count());
+        for (entity, root) in [(a, a), (b, b), (c, b), (d, a)] {
+            let disabled = world.get::<DisabledRecursively<Pooled>>(entity).unwrap();
+            assert_eq!(root, disabled.root());
+        }
+
+        // Enabling `a` leaves the subtree of `b` disabled
+        world.entity_mut(a).enable_recursive::<Children, Pooled>();
+        let mut query = world.query::<Entity>();
+        let mut enabled = query.iter(&world).collect::<Vec<_>>();
+        enabled.sort();
+        let mut expected = vec![a, d];
+        expected.sort();
+        assert_eq!(expected, enabled);
+
+        world.entity_mut(b).enable_recursive::<Children, Pooled>();
+        assert_eq!(4, world.query::<()>().iter(&world).count());
+    }
 }
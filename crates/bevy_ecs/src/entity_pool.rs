@@ -0,0 +1,370 @@
+//! Entity pools recycle entities instead of spawning and despawning them.
+//!
+//! Games often spawn and despawn many short-lived entities, such as projectiles or particles.
+//! Each spawn and despawn moves the entity between archetypes and runs the hooks and observers
+//! of all of its components, which adds up with thousands of entities per second.
+//!
+//! An [`EntityPool`] instead keeps the entities it creates around once they are returned to it,
+//! hidden from queries by the [`InPool`] disabling component,
+//! and hands them out again with the components of its template.
+//!
+//! ```
+//! use bevy_ecs::prelude::*;
+//! use bevy_ecs::entity_pool::EntityPool;
+//!
+//! #[derive(Component, Clone)]
+//! struct Bullet {
+//!     speed: f32,
+//! }
+//!
+//! let mut world = World::new();
+//! world.init_entity_pool(Bullet { speed: 10.0 }, 100);
+//!
+//! fn fire(mut commands: Commands, mut bullets: ResMut<EntityPool<Bullet>>) {
+//!     bullets.take(&mut commands).insert(Name::new("bullet"));
+//! }
+//!
+//! fn hit(mut commands: Commands, bullets: Query<Entity, With<Bullet>>) {
+//!     for bullet in &bullets {
+//!         commands.entity(bullet).return_to_pool::<Bullet>();
+//!     }
+//! }
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems((fire, hit).chain());
+//! schedule.run(&mut world);
+//!
+//! let metrics = world.resource::<EntityPool<Bullet>>().metrics();
+//! assert_eq!(1, metrics.hits);
+//! assert_eq!(0, metrics.misses);
+//! assert_eq!(1, metrics.returns);
+//! ```
+//!
+//! Pooled entities keep the components which were inserted on them outside of the template,
+//! like the [`Name`](crate::name::Name) above, so they should usually be removed before the entity
+//! is returned.
+
+use crate::{
+    bundle::Bundle,
+    change_detection::Mut,
+    component::{Component, HookContext},
+    entity::{Entity, EntityIndexSet},
+    resource::Resource,
+    system::{Commands, EntityCommands},
+    world::{DeferredWorld, EntityWorldMut, World},
+};
+
+/// The disabling component of the entities which are waiting in an [`EntityPool`].
+///
+/// It is stored in a sparse set, so that taking an entity from the pool and returning it
+/// does not move its other components between tables.
+///
+/// When it is removed, or the entity is despawned, the entity is removed from its pool.
+///
+/// See the [`entity_disabling`](crate::entity_disabling) module docs for more info
+/// on disabling components.
+#[derive(Component, Clone, Debug)]
+#[component(storage = "SparseSet", on_remove = remove_from_pool)]
+pub struct InPool {
+    remove: fn(&mut DeferredWorld, Entity),
+}
+
+impl InPool {
+    fn new<B: Bundle + Clone>() -> Self {
+        Self {
+            remove: |world, entity| {
+                if let Some(mut pool) = world.get_resource_mut::<EntityPool<B>>() {
+                    pool.available.swap_remove(&entity);
+                }
+            },
+        }
+    }
+}
+
+fn remove_from_pool(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if let Some(remove) = world.get::<InPool>(entity).map(|in_pool| in_pool.remove) {
+        remove(&mut world, entity);
+    }
+}
+
+/// The hits, misses and returns of an [`EntityPool`], which can be used to tune its size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntityPoolMetrics {
+    /// The number of entities taken from the pool.
+    pub hits: u64,
+    /// The number of entities spawned because the pool was empty.
+    pub misses: u64,
+    /// The number of entities returned to the pool.
+    pub returns: u64,
+}
+
+impl EntityPoolMetrics {
+    /// Returns the share of the requested entities which were taken from the pool,
+    /// or `1.0` if none were requested.
+    pub fn hit_rate(&self) -> f32 {
+        let requests = self.hits + self.misses;
+        if requests == 0 {
+            1.0
+        } else {
+            self.hits as f32 / requests as f32
+        }
+    }
+}
+
+/// A pool of entities created from the template `B`, created with [`World::init_entity_pool`].
+///
+/// Entities are taken from the pool with [`EntityPool::take`] or [`World::spawn_pooled`],
+/// which reinsert the template on them, and are returned to it with
+/// [`EntityCommands::return_to_pool`] or [`EntityWorldMut::return_to_pool`].
+/// When the pool is empty, new entities are spawned instead.
+///
+/// Entities which are despawned while in the pool are removed from it.
+///
+/// See the [module docs](crate::entity_pool) for more info.
+#[derive(Resource)]
+pub struct EntityPool<B: Bundle + Clone> {
+    template: B,
+    available: EntityIndexSet,
+    metrics: EntityPoolMetrics,
+}
+
+impl<B: Bundle + Clone> EntityPool<B> {
+    /// Returns the bundle inserted on the entities handed out by the pool.
+    pub fn template(&self) -> &B {
+        &self.template
+    }
+
+    /// Sets the bundle inserted on the entities handed out by the pool.
+    pub fn set_template(&mut self, template: B) {
+        self.template = template;
+    }
+
+    /// Returns the number of entities waiting in the pool.
+    pub fn available(&self) -> usize {
+        self.available.len()
+    }
+
+    /// Returns the hits, misses and returns of the pool.
+    pub fn metrics(&self) -> EntityPoolMetrics {
+        self.metrics
+    }
+
+    /// Resets the [metrics](Self::metrics) of the pool.
+    pub fn reset_metrics(&mut self) {
+        self.metrics = EntityPoolMetrics::default();
+    }
+
+    /// Takes an entity from the pool, or spawns a new one if it is empty,
+    /// and queues the insertion of the template on it.
+    pub fn take<'a>(&mut self, commands: &'a mut Commands) -> EntityCommands<'a> {
+        match self.pop() {
+            Some(entity) => {
+                let mut entity_commands = commands.entity(entity);
+                entity_commands
+                    .insert(self.template.clone())
+                    .remove::<InPool>();
+                entity_commands
+            }
+            None => commands.spawn(self.template.clone()),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Entity> {
+        let entity = self.available.pop();
+        if entity.is_some() {
+            self.metrics.hits += 1;
+        } else {
+            self.metrics.misses += 1;
+        }
+        entity
+    }
+}
+
+impl World {
+    /// Creates the [`EntityPool`] of the template `B`, spawning `count` entities in it,
+    /// and registers [`InPool`] as a disabling component.
+    ///
+    /// If the pool already exists, its template is replaced and `count` entities are added to it.
+    pub fn init_entity_pool<B: Bundle + Clone>(&mut self, template: B, count: usize) {
+        self.register_disabling_component::<InPool>();
+        let (mut available, metrics) = self
+            .remove_resource::<EntityPool<B>>()
+            .map(|pool| (pool.available, pool.metrics))
+            .unwrap_or_default();
+        available.reserve(count);
+        for _ in 0..count {
+            available.insert(self.spawn((template.clone(), InPool::new::<B>())).id());
+        }
+        self.insert_resource(EntityPool {
+            template,
+            available,
+            metrics,
+        });
+    }
+
+    /// Takes an entity from the [`EntityPool`] of `B`, or spawns a new one if it is empty,
+    /// and inserts the template on it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pool was not created with [`World::init_entity_pool`].
+    pub fn spawn_pooled<B: Bundle + Clone>(&mut self) -> EntityWorldMut<'_> {
+        let mut pool = self.pool_mut::<B>();
+        let template = pool.template.clone();
+        let entity = pool.available.pop();
+        // Entities despawned while the pool resource was removed can still be in it.
+        let entity = entity.filter(|&entity| self.get_entity_mut(entity).is_ok());
+        let mut pool = self.pool_mut::<B>();
+        match entity {
+            Some(entity) => {
+                pool.metrics.hits += 1;
+                let mut entity = self.entity_mut(entity);
+                entity.insert(template).remove::<InPool>();
+                entity
+            }
+            None => {
+                pool.metrics.misses += 1;
+                self.spawn(template)
+            }
+        }
+    }
+
+    fn pool_mut<B: Bundle + Clone>(&mut self) -> Mut<'_, EntityPool<B>> {
+        self.get_resource_mut::<EntityPool<B>>().unwrap_or_else(|| {
+            panic!(
+                "The entity pool of {} does not exist, it should be created with `World::init_entity_pool`.",
+                core::any::type_name::<B>()
+            )
+        })
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Returns this entity to the [`EntityPool`] of `B`, hiding it from queries with [`InPool`]
+    /// until it is taken again. Does nothing if the entity is already in a pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pool was not created with [`World::init_entity_pool`].
+    pub fn return_to_pool<B: Bundle + Clone>(&mut self) -> &mut Self {
+        if self.contains::<InPool>() {
+            return self;
+        }
+        self.insert(InPool::new::<B>());
+        let entity = self.id();
+        self.world_scope(|world| {
+            let mut pool = world.pool_mut::<B>();
+            pool.available.insert(entity);
+            pool.metrics.returns += 1;
+        });
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Returns this entity to the [`EntityPool`] of `B`. See [`EntityWorldMut::return_to_pool`].
+    pub fn return_to_pool<B: Bundle + Clone>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.return_to_pool::<B>();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityPool, EntityPoolMetrics, InPool};
+    use crate::{
+        component::Component,
+        entity::Entity,
+        system::{Commands, ResMut, RunSystemOnce},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Projectile {
+        speed: f32,
+    }
+
+    #[test]
+    fn entity_pool() {
+        let mut world = World::new();
+        world.init_entity_pool(Projectile { speed: 10.0 }, 2);
+        assert_eq!(2, world.resource::<EntityPool<Projectile>>().available());
+        assert_eq!(0, world.query::<&Projectile>().iter(&world).count());
+
+        let a = world.spawn_pooled::<Projectile>().id();
+        world.get_mut::<Projectile>(a).unwrap().speed = 0.0;
+        let b = world.spawn_pooled::<Projectile>().id();
+        let c = world.spawn_pooled::<Projectile>().id();
+        assert_eq!(3, world.query::<&Projectile>().iter(&world).count());
+
+        world.entity_mut(a).return_to_pool::<Projectile>();
+        // Returning an entity twice does nothing
+        world.entity_mut(a).return_to_pool::<Projectile>();
+        world.entity_mut(c).return_to_pool::<Projectile>();
+        assert!(world.entity(a).contains::<InPool>());
+        assert_eq!(
+            vec![b],
+            world.query::<Entity>().iter(&world).collect::<Vec<_>>()
+        );
+
+        let d = world.spawn_pooled::<Projectile>().id();
+        let e = world.spawn_pooled::<Projectile>().id();
+        assert_eq!(c, d);
+        assert_eq!(a, e);
+        // The template is reinserted on the entities taken from the pool
+        assert_eq!(
+            Some(&Projectile { speed: 10.0 }),
+            world.get::<Projectile>(a)
+        );
+
+        let pool = world.resource::<EntityPool<Projectile>>();
+        assert_eq!(0, pool.available());
+        assert_eq!(
+            EntityPoolMetrics {
+                hits: 4,
+                misses: 1,
+                returns: 2,
+            },
+            pool.metrics()
+        );
+        assert_eq!(0.8, pool.metrics().hit_rate());
+    }
+
+    #[test]
+    fn despawned_entities_leave_the_pool() {
+        let mut world = World::new();
+        world.init_entity_pool(Projectile { speed: 10.0 }, 3);
+        let pooled = world
+            .query_filtered::<Entity, crate::query::With<InPool>>()
+            .iter(&world)
+            .collect::<Vec<_>>();
+
+        world.despawn(pooled[0]);
+        assert_eq!(2, world.resource::<EntityPool<Projectile>>().available());
+
+        world
+            .run_system_once(
+                |mut commands: Commands, mut pool: ResMut<EntityPool<Projectile>>| {
+                    pool.take(&mut commands);
+                    pool.take(&mut commands);
+                },
+            )
+            .unwrap();
+        assert_eq!(2, world.query::<&Projectile>().iter(&world).count());
+
+        // Entities despawned while the pool resource is removed are skipped
+        world.init_entity_pool(Projectile { speed: 10.0 }, 1);
+        let pool = world.remove_resource::<EntityPool<Projectile>>().unwrap();
+        let pooled = pool.available[0];
+        world.despawn(pooled);
+        world.insert_resource(pool);
+        let spawned = world.spawn_pooled::<Projectile>().id();
+        assert_ne!(pooled, spawned);
+
+        let metrics = world.resource::<EntityPool<Projectile>>().metrics();
+        assert_eq!(2, metrics.hits);
+        assert_eq!(1, metrics.misses);
+    }
+}
@@ -5,6 +5,7 @@ mod event_cursor;
 mod iterators;
 mod mut_iterators;
 mod mutator;
+mod persistent;
 mod reader;
 mod registry;
 mod update;
@@ -22,6 +23,10 @@ pub use iterators::{EventIterator, EventIteratorWithId};
 pub use mut_iterators::EventMutParIter;
 pub use mut_iterators::{EventMutIterator, EventMutIteratorWithId};
 pub use mutator::EventMutator;
+pub use persistent::{
+    PersistentEventReader, PersistentEventReaderState, PersistentEventWriter, PersistentEvents,
+    PersistentEventsFull,
+};
 pub use reader::EventReader;
 pub use registry::{EventRegistry, ShouldUpdateEvents};
 pub use update::{
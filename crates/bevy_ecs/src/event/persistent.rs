@@ -0,0 +1,466 @@
+use alloc::{borrow::Cow, collections::VecDeque, vec::Vec};
+use bevy_ecs::{
+    component::{ComponentId, Tick},
+    event::Event,
+    resource::Resource,
+    system::{ReadOnlySystemParam, Res, ResMut, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use bevy_platform_support::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+use log::warn;
+use thiserror::Error;
+
+/// A channel of events of type `E` which are kept until every registered reader has
+/// acknowledged them.
+///
+/// Unlike [`Events`](super::Events), which drops the events that were not read within two
+/// updates, [`PersistentEvents`] never drops an event before all of its readers are done with it.
+/// This makes it suited to events which must not be missed, such as damage or quest progress,
+/// at the cost of growing as long as a reader lags behind. To bound this, the channel can be
+/// given a [capacity](Self::with_capacity), after which sending fails until the slowest reader
+/// catches up, and a [lag threshold](Self::with_lag_warning) after which a warning naming the
+/// lagging reader is logged.
+///
+/// Readers are registered when a system with a [`PersistentEventReader<E>`] is initialized, and
+/// unregistered when it is dropped. Events sent while no reader is registered are dropped, so the
+/// channel should be set up along with its readers, usually before the app runs.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::event::{PersistentEventReader, PersistentEventWriter, PersistentEvents};
+/// #[derive(Event)]
+/// struct QuestProgress(u32);
+///
+/// fn complete_objective(mut writer: PersistentEventWriter<QuestProgress>) {
+///     if writer.send(QuestProgress(1)).is_err() {
+///         // The channel is full: retry next frame.
+///     }
+/// }
+///
+/// fn update_journal(mut reader: PersistentEventReader<QuestProgress>) {
+///     for progress in reader.read() {
+///         // ...
+///     }
+///     reader.ack();
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource(PersistentEvents::<QuestProgress>::with_capacity(64));
+/// # bevy_ecs::system::assert_is_system(complete_objective);
+/// # bevy_ecs::system::assert_is_system(update_journal);
+/// ```
+#[derive(Resource, Debug)]
+pub struct PersistentEvents<E: Event> {
+    events: VecDeque<E>,
+    /// The id of the first event of `events`.
+    start: usize,
+    readers: Vec<Arc<PersistentReaderCursor>>,
+    capacity: Option<usize>,
+    lag_warning: Option<usize>,
+}
+
+impl<E: Event> Default for PersistentEvents<E> {
+    fn default() -> Self {
+        Self {
+            events: VecDeque::new(),
+            start: 0,
+            readers: Vec::new(),
+            capacity: None,
+            lag_warning: None,
+        }
+    }
+}
+
+/// The error returned when sending an event to a full [`PersistentEvents`] channel. It contains
+/// the rejected event.
+#[derive(Error, Debug)]
+#[error("The persistent event channel of {} is full", core::any::type_name::<E>())]
+pub struct PersistentEventsFull<E: Event>(pub E);
+
+#[derive(Debug)]
+struct PersistentReaderCursor {
+    name: Cow<'static, str>,
+    /// The id of the first event this reader has not acknowledged.
+    acked: AtomicUsize,
+    warned: AtomicBool,
+}
+
+impl<E: Event> PersistentEvents<E> {
+    /// Creates a channel without a capacity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a channel holding at most `capacity` events.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Logs a warning when a reader has more than `lag` events left to acknowledge.
+    pub fn with_lag_warning(mut self, lag: usize) -> Self {
+        self.lag_warning = Some(lag);
+        self
+    }
+
+    /// Returns the maximum number of events held by the channel, if any.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Returns the number of events held by the channel, including the events acknowledged by
+    /// all readers since the last [`update`](Self::update).
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if the channel holds no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the number of registered readers.
+    pub fn reader_count(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns the name and the number of events left to acknowledge of each registered reader.
+    pub fn reader_lags(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
+        let end = self.end();
+        self.readers
+            .iter()
+            .map(move |reader| (&*reader.name, end - reader.acked.load(Ordering::Acquire)))
+    }
+
+    /// Sends an `event` to every registered reader.
+    ///
+    /// This first [updates](Self::update) the channel, and fails if it is still at its capacity.
+    pub fn send(&mut self, event: E) -> Result<(), PersistentEventsFull<E>> {
+        self.update();
+        if self.readers.is_empty() {
+            return Ok(());
+        }
+        if self
+            .capacity
+            .is_some_and(|capacity| self.events.len() >= capacity)
+        {
+            if let Some((name, _)) = self.reader_lags().max_by_key(|(_, lag)| *lag) {
+                warn!(
+                    "The persistent event channel of {} is full, the reader {} is lagging behind.",
+                    core::any::type_name::<E>(),
+                    name
+                );
+            }
+            return Err(PersistentEventsFull(event));
+        }
+        self.events.push_back(event);
+        self.warn_lagging_readers();
+        Ok(())
+    }
+
+    /// Drops the events acknowledged by every reader, and unregisters the readers of systems
+    /// which were dropped. This is done automatically when sending events.
+    pub fn update(&mut self) {
+        self.readers.retain(|reader| Arc::strong_count(reader) > 1);
+        let acked = self
+            .readers
+            .iter()
+            .map(|reader| reader.acked.load(Ordering::Acquire))
+            .min()
+            .unwrap_or(self.end());
+        let dropped = acked - self.start;
+        self.events.drain(..dropped);
+        self.start = acked;
+    }
+
+    fn end(&self) -> usize {
+        self.start + self.events.len()
+    }
+
+    fn warn_lagging_readers(&self) {
+        let Some(threshold) = self.lag_warning else {
+            return;
+        };
+        let end = self.end();
+        for reader in &self.readers {
+            let lag = end - reader.acked.load(Ordering::Acquire);
+            if lag <= threshold {
+                reader.warned.store(false, Ordering::Relaxed);
+            } else if !reader.warned.swap(true, Ordering::Relaxed) {
+                warn!(
+                    "The reader {} of the persistent event channel of {} has {} events left to acknowledge.",
+                    reader.name,
+                    core::any::type_name::<E>(),
+                    lag
+                );
+            }
+        }
+    }
+
+    fn register_reader(&mut self, name: Cow<'static, str>) -> Arc<PersistentReaderCursor> {
+        let cursor = Arc::new(PersistentReaderCursor {
+            name,
+            acked: AtomicUsize::new(self.start),
+            warned: AtomicBool::new(false),
+        });
+        self.readers.push(cursor.clone());
+        cursor
+    }
+}
+
+/// Reads events of type `E` from a [`PersistentEvents<E>`] channel.
+///
+/// Each system with a [`PersistentEventReader`] is registered as a reader of the channel when it
+/// is initialized, which creates the channel if needed. Read events are kept until they are
+/// explicitly acknowledged with [`ack`](Self::ack), and can be read again before that with
+/// [`rewind`](Self::rewind), for example if they could not be handled yet.
+///
+/// Readers only need read access to the channel, so they can run in parallel.
+pub struct PersistentEventReader<'w, 's, E: Event> {
+    events: Res<'w, PersistentEvents<E>>,
+    state: &'s mut PersistentEventReaderState,
+}
+
+#[doc(hidden)]
+pub struct PersistentEventReaderState {
+    component_id: ComponentId,
+    cursor: Arc<PersistentReaderCursor>,
+    /// The id of the first event this reader has not read.
+    read: usize,
+}
+
+impl<'w, 's, E: Event> PersistentEventReader<'w, 's, E> {
+    /// Returns the events which were not read yet by this reader.
+    pub fn read(&mut self) -> impl ExactSizeIterator<Item = &E> + '_ {
+        let from = self.state.read - self.events.start;
+        self.state.read = self.events.end();
+        self.events.events.range(from..)
+    }
+
+    /// Acknowledges every event read so far, allowing the channel to drop them.
+    pub fn ack(&mut self) {
+        self.state
+            .cursor
+            .acked
+            .store(self.state.read, Ordering::Release);
+    }
+
+    /// Reads again the events which were not acknowledged yet.
+    pub fn rewind(&mut self) {
+        self.state.read = self.state.cursor.acked.load(Ordering::Acquire);
+    }
+
+    /// Returns the number of events which were not read yet by this reader.
+    pub fn len(&self) -> usize {
+        self.events.end() - self.state.read
+    }
+
+    /// Returns `true` if this reader has read every event of the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of events read, but not acknowledged yet by this reader.
+    pub fn unacknowledged(&self) -> usize {
+        self.state.read - self.state.cursor.acked.load(Ordering::Acquire)
+    }
+}
+
+// SAFETY: defers to `Res`, which only reads a single resource.
+unsafe impl<'w, 's, E: Event> ReadOnlySystemParam for PersistentEventReader<'w, 's, E> {}
+
+// SAFETY: defers to `Res`, which initializes and validates the correct world access.
+unsafe impl<'w, 's, E: Event> SystemParam for PersistentEventReader<'w, 's, E> {
+    type State = PersistentEventReaderState;
+    type Item<'world, 'state> = PersistentEventReader<'world, 'state, E>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let cursor = world
+            .get_resource_or_init::<PersistentEvents<E>>()
+            .register_reader(system_meta.name.clone());
+        let component_id = <Res<PersistentEvents<E>>>::init_state(world, system_meta);
+        PersistentEventReaderState {
+            component_id,
+            read: cursor.acked.load(Ordering::Acquire),
+            cursor,
+        }
+    }
+
+    #[inline]
+    unsafe fn validate_param(
+        state: &Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell,
+    ) -> bool {
+        // SAFETY: upheld by the caller.
+        unsafe {
+            <Res<PersistentEvents<E>>>::validate_param(&state.component_id, system_meta, world)
+        }
+    }
+
+    #[inline]
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        // SAFETY: upheld by the caller.
+        let events = unsafe {
+            <Res<PersistentEvents<E>>>::get_param(
+                &mut state.component_id,
+                system_meta,
+                world,
+                change_tick,
+            )
+        };
+        PersistentEventReader { events, state }
+    }
+}
+
+/// Sends events of type `E` to a [`PersistentEvents<E>`] channel.
+#[derive(SystemParam)]
+pub struct PersistentEventWriter<'w, E: Event> {
+    events: ResMut<'w, PersistentEvents<E>>,
+}
+
+impl<'w, E: Event> PersistentEventWriter<'w, E> {
+    /// Sends an `event` to every registered reader, failing if the channel is full.
+    ///
+    /// See [`PersistentEvents::send`].
+    pub fn send(&mut self, event: E) -> Result<(), PersistentEventsFull<E>> {
+        self.events.send(event)
+    }
+
+    /// Returns `true` if the channel is at its capacity, once the events acknowledged by every
+    /// reader are dropped.
+    pub fn is_full(&mut self) -> bool {
+        self.events.update();
+        self.events
+            .capacity
+            .is_some_and(|capacity| self.events.len() >= capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PersistentEventReader, PersistentEventWriter, PersistentEvents};
+    use crate::{
+        event::Event,
+        resource::Resource,
+        schedule::{IntoSystemConfigs, Schedule},
+        system::{Local, ResMut},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Event, Clone, Copy, PartialEq, Eq, Debug)]
+    struct Damage(u32);
+
+    #[derive(Resource, Default)]
+    struct Received {
+        fast: Vec<u32>,
+        slow: Vec<u32>,
+        rejected: Vec<u32>,
+    }
+
+    #[test]
+    fn persistent_events() {
+        let mut world = World::new();
+        world.insert_resource(PersistentEvents::<Damage>::with_capacity(3));
+        world.init_resource::<Received>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                |mut writer: PersistentEventWriter<Damage>,
+                 mut received: ResMut<Received>,
+                 mut next: Local<u32>| {
+                    match writer.send(Damage(*next)) {
+                        Ok(()) => *next += 1,
+                        Err(rejected) => received.rejected.push(rejected.0 .0),
+                    }
+                },
+                |mut reader: PersistentEventReader<Damage>, mut received: ResMut<Received>| {
+                    received.fast.extend(reader.read().map(|damage| damage.0));
+                    reader.ack();
+                },
+                // Only acknowledges the events every fourth run.
+                |mut reader: PersistentEventReader<Damage>,
+                 mut received: ResMut<Received>,
+                 mut run: Local<u32>| {
+                    received.slow.extend(reader.read().map(|damage| damage.0));
+                    *run += 1;
+                    if *run % 4 == 0 {
+                        reader.ack();
+                    }
+                },
+            )
+                .chain(),
+        );
+        for _ in 0..5 {
+            schedule.run(&mut world);
+        }
+
+        let received = world.resource::<Received>();
+        assert_eq!(received.fast, vec![0, 1, 2, 3]);
+        assert_eq!(received.slow, vec![0, 1, 2, 3]);
+        assert_eq!(received.rejected, vec![3]);
+
+        let events = world.resource::<PersistentEvents<Damage>>();
+        assert_eq!(events.reader_count(), 2);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events.reader_lags().map(|(_, lag)| lag).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn persistent_events_rewind() {
+        let mut world = World::new();
+        world.init_resource::<PersistentEvents<Damage>>();
+        world.init_resource::<Received>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            |mut reader: PersistentEventReader<Damage>,
+             mut received: ResMut<Received>,
+             mut run: Local<u32>| {
+                received.fast.extend(reader.read().map(|damage| damage.0));
+                *run += 1;
+                // The events could not be handled the first time they were read.
+                if *run == 2 {
+                    reader.rewind();
+                } else {
+                    reader.ack();
+                }
+            },
+        );
+        schedule.run(&mut world);
+
+        let mut events = world.resource_mut::<PersistentEvents<Damage>>();
+        events.send(Damage(0)).unwrap();
+        events.send(Damage(1)).unwrap();
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        world
+            .resource_mut::<PersistentEvents<Damage>>()
+            .send(Damage(2))
+            .unwrap();
+        schedule.run(&mut world);
+
+        assert_eq!(world.resource::<Received>().fast, vec![0, 1, 0, 1, 2]);
+
+        drop(schedule);
+        let mut events = world.resource_mut::<PersistentEvents<Damage>>();
+        events.update();
+        assert_eq!(events.reader_count(), 0);
+        assert!(events.is_empty());
+    }
+}
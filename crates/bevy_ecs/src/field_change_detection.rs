@@ -0,0 +1,432 @@
+//! Change detection on the individual fields of a component.
+//!
+//! [`Changed<T>`](crate::query::Changed) reports the whole component as changed as soon as it is
+//! mutably dereferenced, even if only one of its fields was modified. Components can opt in to
+//! per-field change detection with the `track_fields` attribute of the [`Component`] derive,
+//! which takes the name of a module to generate with a marker type for each field:
+//!
+//! ```
+//! use bevy_ecs::prelude::*;
+//! use bevy_ecs::field_change_detection::{ChangedField, FieldsMut};
+//!
+//! #[derive(Component, Default)]
+//! #[component(track_fields = transform_fields)]
+//! struct Transform {
+//!     translation: [f32; 3],
+//!     scale: f32,
+//! }
+//!
+//! use transform_fields::{scale, translation};
+//!
+//! fn movement(mut transforms: Query<FieldsMut<Transform>>) {
+//!     for mut transform in &mut transforms {
+//!         // Only marks `translation` as changed
+//!         transform.field_mut::<translation>()[0] += 1.0;
+//!     }
+//! }
+//!
+//! fn rescale(transforms: Query<&Transform, ChangedField<Transform, scale>>) {
+//!     for transform in &transforms {
+//!         // Only runs for the transforms whose scale changed
+//!     }
+//! }
+//! # fn main() {
+//! # bevy_ecs::system::assert_is_system(movement);
+//! # bevy_ecs::system::assert_is_system(rescale);
+//! # }
+//! ```
+//!
+//! As the module is generated next to the component, the component cannot be declared inside a
+//! function body.
+//!
+//! The change ticks of the fields are stored in a [`FieldTicks`] component, which is required by
+//! the component. Only [`FieldsMutItem::field_mut`] updates them: any other mutable access to the
+//! component, for example through `Query<&mut Transform>`, marks all of its fields as changed.
+//! Inserting the component also marks all of its fields as changed.
+//!
+//! Whole modifications are told apart from field modifications by their change tick. A whole
+//! modification made at the same change tick as a previous [`FieldsMutItem::field_mut`] on the
+//! same component is therefore reported as a modification of that field only. This does not
+//! happen across systems, which each run at their own change tick, but does when accessing the
+//! [`World`] directly, which does not advance the change tick between accesses, or when
+//! modifying the component in both parts of a [`ParamSet`](crate::system::ParamSet).
+//! Use [`FieldsMutItem::component_mut`] to modify the whole component in these cases.
+
+use crate::{
+    archetype::Archetype,
+    change_detection::{DetectChanges, DetectChangesMut, Mut, Ref},
+    component::{Component, ComponentId, Components, Mutable, Tick},
+    entity::Entity,
+    query::{FilteredAccess, QueryData, QueryFilter, WorldQuery},
+    storage::{Table, TableRow},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use core::{fmt::Debug, marker::PhantomData};
+use smallvec::SmallVec;
+
+/// A component whose fields have their own change ticks, implemented by the `track_fields`
+/// attribute of the [`Component`] derive.
+///
+/// See the [module docs](crate::field_change_detection) for more info.
+pub trait TrackedFields: Component<Mutability = Mutable> {
+    /// The names of the fields of the component, in the order of their [`ComponentField::INDEX`].
+    const FIELDS: &'static [&'static str];
+}
+
+/// A field of a [`TrackedFields`] component, implemented by the marker types generated by the
+/// `track_fields` attribute of the [`Component`] derive.
+pub trait ComponentField: 'static {
+    /// The component this field belongs to.
+    type Component: TrackedFields;
+    /// The type of the field.
+    type Type;
+    /// The index of the field in [`TrackedFields::FIELDS`].
+    const INDEX: usize;
+
+    /// Returns a reference to the field of `component`.
+    fn get(component: &Self::Component) -> &Self::Type;
+
+    /// Returns a mutable reference to the field of `component`.
+    fn get_mut(component: &mut Self::Component) -> &mut Self::Type;
+}
+
+/// The change ticks of the fields of the [`TrackedFields`] component `T`, which is inserted
+/// alongside it as a required component.
+#[derive(Component)]
+pub struct FieldTicks<T: TrackedFields> {
+    /// The change tick of the component when a field was last modified through [`FieldsMut`].
+    /// If the component changed since, it was modified as a whole. Whole modifications made at
+    /// this same tick cannot be detected, see the [module docs](crate::field_change_detection).
+    written: Tick,
+    ticks: SmallVec<[Tick; 8]>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: TrackedFields> FieldTicks<T> {
+    /// Returns the tick of the last modification of the field `F` through [`FieldsMut`].
+    ///
+    /// Note that this does not account for the modifications of the component as a whole,
+    /// use [`FieldTicks::is_field_changed`] to check whether the field changed.
+    pub fn field_tick<F: ComponentField<Component = T>>(&self) -> Tick {
+        self.ticks[F::INDEX]
+    }
+
+    /// Returns `true` if the field `F` of the component changed after `last_run`,
+    /// given the [`Ref`] to the component.
+    pub fn is_field_changed<F: ComponentField<Component = T>>(&self, component: &Ref<T>) -> bool {
+        self.is_changed_since(
+            F::INDEX,
+            component.last_changed(),
+            component.ticks.last_run,
+            component.ticks.this_run,
+        )
+    }
+
+    fn is_changed_since(
+        &self,
+        index: usize,
+        component_changed: Tick,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> bool {
+        component_changed.is_newer_than(last_run, this_run)
+            && (self.written != component_changed
+                || self.ticks[index].is_newer_than(last_run, this_run))
+    }
+
+    fn mark(&mut self, index: usize, component_changed: Tick, this_run: Tick) {
+        if self.written != component_changed {
+            // The component was modified as a whole since a field was last modified.
+            self.ticks.fill(component_changed);
+        }
+        self.written = this_run;
+        self.ticks[index] = this_run;
+        for tick in &mut self.ticks {
+            tick.check_tick(this_run);
+        }
+    }
+}
+
+impl<T: TrackedFields> Default for FieldTicks<T> {
+    fn default() -> Self {
+        Self {
+            written: Tick::default(),
+            ticks: SmallVec::from_elem(Tick::default(), T::FIELDS.len()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: TrackedFields> Clone for FieldTicks<T> {
+    fn clone(&self) -> Self {
+        Self {
+            written: self.written,
+            ticks: self.ticks.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: TrackedFields> Debug for FieldTicks<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(T::FIELDS.iter().zip(&self.ticks))
+            .finish()
+    }
+}
+
+/// [`QueryData`] giving mutable access to the fields of the [`TrackedFields`] component `T`,
+/// which only marks the modified fields as changed.
+///
+/// See the [module docs](crate::field_change_detection) for more info.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct FieldsMut<T: TrackedFields> {
+    component: &'static mut T,
+    ticks: &'static mut FieldTicks<T>,
+}
+
+impl<'w, T: TrackedFields> FieldsMutItem<'w, T> {
+    /// Returns a reference to the component.
+    pub fn get(&self) -> &T {
+        &self.component
+    }
+
+    /// Returns a reference to the field `F` of the component.
+    pub fn field<F: ComponentField<Component = T>>(&self) -> &F::Type {
+        F::get(&self.component)
+    }
+
+    /// Returns a mutable reference to the field `F` of the component,
+    /// marking the field and the component as changed.
+    pub fn field_mut<F: ComponentField<Component = T>>(&mut self) -> &mut F::Type {
+        let ticks = &self.component.ticks;
+        let (changed, this_run) = (*ticks.changed, ticks.this_run);
+        self.ticks
+            .bypass_change_detection()
+            .mark(F::INDEX, changed, this_run);
+        F::get_mut(self.component.as_mut())
+    }
+
+    /// Returns mutable access to the whole component,
+    /// which marks all of its fields as changed once it is mutably dereferenced.
+    pub fn component_mut(&mut self) -> Mut<'_, T> {
+        self.ticks.bypass_change_detection().written = Tick::default();
+        self.component.reborrow()
+    }
+
+    /// Returns `true` if the field `F` changed since the last time the system ran.
+    pub fn is_field_changed<F: ComponentField<Component = T>>(&self) -> bool {
+        let ticks = &self.component.ticks;
+        self.ticks
+            .is_changed_since(F::INDEX, *ticks.changed, ticks.last_run, ticks.this_run)
+    }
+}
+
+/// A filter on a field of a component that only retains results the first time after the field
+/// of the component was modified, or the component was added or mutably dereferenced as a whole.
+///
+/// The component must opt in to per-field change detection with the `track_fields` attribute of
+/// the [`Component`] derive, and the field must be modified through [`FieldsMut`] to only mark
+/// this field as changed. See the [module docs](crate::field_change_detection) for more info.
+pub struct ChangedField<T, F>(PhantomData<(T, F)>);
+
+type ChangedFieldData<T> = (Ref<'static, T>, &'static FieldTicks<T>);
+
+// SAFETY: `update_component_access` and `update_archetype_component_access` are delegated to
+// the read-only query data `ChangedFieldData<T>`, which is the only data accessed.
+unsafe impl<T: TrackedFields, F: ComponentField<Component = T>> WorldQuery for ChangedField<T, F> {
+    type Fetch<'w> = <ChangedFieldData<T> as WorldQuery>::Fetch<'w>;
+    type State = <ChangedFieldData<T> as WorldQuery>::State;
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        <ChangedFieldData<T> as WorldQuery>::shrink_fetch(fetch)
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { <ChangedFieldData<T> as WorldQuery>::init_fetch(world, state, last_run, this_run) }
+    }
+
+    const IS_DENSE: bool = <ChangedFieldData<T> as WorldQuery>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe {
+            <ChangedFieldData<T> as WorldQuery>::set_archetype(fetch, state, archetype, table);
+        }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { <ChangedFieldData<T> as WorldQuery>::set_table(fetch, state, table) }
+    }
+
+    #[inline]
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        <ChangedFieldData<T> as WorldQuery>::update_component_access(state, access);
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        <ChangedFieldData<T> as WorldQuery>::init_state(world)
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        <ChangedFieldData<T> as WorldQuery>::get_state(components)
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        <ChangedFieldData<T> as WorldQuery>::matches_component_set(state, set_contains_id)
+    }
+}
+
+// SAFETY: WorldQuery impl performs only read access on the component and its field ticks
+unsafe impl<T: TrackedFields, F: ComponentField<Component = T>> QueryFilter for ChangedField<T, F> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are upheld by the caller.
+        let (component, ticks) =
+            unsafe { <ChangedFieldData<T> as QueryData>::fetch(fetch, entity, table_row) };
+        ticks.is_field_changed::<F>(&component)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChangedField, FieldTicks, FieldsMut};
+    use crate::{
+        component::Component,
+        query::Changed,
+        system::{Query, RunSystemOnce},
+        world::World,
+    };
+
+    #[derive(Component, Default, Clone, Copy, PartialEq, Debug)]
+    #[component(track_fields = transform_fields)]
+    struct Transform {
+        translation: f32,
+        rotation: f32,
+        scale: f32,
+    }
+
+    use transform_fields::{rotation, scale, translation};
+
+    fn changed_fields(world: &mut World) -> (usize, usize, usize, usize) {
+        let mut translations = world.query_filtered::<(), ChangedField<Transform, translation>>();
+        let mut rotations = world.query_filtered::<(), ChangedField<Transform, rotation>>();
+        let mut scales = world.query_filtered::<(), ChangedField<Transform, scale>>();
+        let mut changed = world.query_filtered::<(), Changed<Transform>>();
+        let counts = (
+            translations.iter(world).count(),
+            rotations.iter(world).count(),
+            scales.iter(world).count(),
+            changed.iter(world).count(),
+        );
+        world.clear_trackers();
+        counts
+    }
+
+    #[test]
+    fn field_change_detection() {
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        world.spawn(Transform::default());
+        assert!(world.entity(entity).contains::<FieldTicks<Transform>>());
+        // Inserting the component changes all of its fields
+        assert_eq!((2, 2, 2, 2), changed_fields(&mut world));
+        assert_eq!((0, 0, 0, 0), changed_fields(&mut world));
+
+        world
+            .run_system_once(|mut query: Query<FieldsMut<Transform>>| {
+                for mut transform in &mut query {
+                    *transform.field_mut::<translation>() += 1.0;
+                    assert!(transform.is_field_changed::<translation>());
+                }
+            })
+            .unwrap();
+        assert_eq!((2, 0, 0, 2), changed_fields(&mut world));
+
+        world
+            .run_system_once(move |mut query: Query<FieldsMut<Transform>>| {
+                let mut transform = query.get_mut(entity).unwrap();
+                *transform.field_mut::<scale>() = 2.0;
+                *transform.field_mut::<rotation>() = 0.5;
+            })
+            .unwrap();
+        assert_eq!((0, 1, 1, 1), changed_fields(&mut world));
+
+        // Modifying the component as a whole changes all of its fields
+        world.get_mut::<Transform>(entity).unwrap().translation = 3.0;
+        assert_eq!((1, 1, 1, 1), changed_fields(&mut world));
+        world
+            .run_system_once(move |mut query: Query<FieldsMut<Transform>>| {
+                let mut transform = query.get_mut(entity).unwrap();
+                transform.component_mut().scale = 1.0;
+            })
+            .unwrap();
+        assert_eq!((1, 1, 1, 1), changed_fields(&mut world));
+
+        assert_eq!(
+            &Transform {
+                translation: 3.0,
+                rotation: 0.5,
+                scale: 1.0,
+            },
+            world.get::<Transform>(entity).unwrap()
+        );
+    }
+
+    #[test]
+    fn whole_modification_at_the_same_tick() {
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        changed_fields(&mut world);
+
+        // Direct world access does not advance the change tick, so the whole modification
+        // following the field modification cannot be told apart from it.
+        let mut query = world.query::<FieldsMut<Transform>>();
+        *query
+            .get_mut(&mut world, entity)
+            .unwrap()
+            .field_mut::<scale>() = 2.0;
+        world.get_mut::<Transform>(entity).unwrap().translation = 1.0;
+        assert_eq!((0, 0, 1, 1), changed_fields(&mut world));
+
+        // `component_mut` marks all fields as changed regardless of the tick.
+        let mut fields = query.get_mut(&mut world, entity).unwrap();
+        *fields.field_mut::<scale>() = 3.0;
+        fields.component_mut().translation = 2.0;
+        assert_eq!((1, 1, 1, 1), changed_fields(&mut world));
+
+        // At a later tick, the whole modification is detected.
+        *query
+            .get_mut(&mut world, entity)
+            .unwrap()
+            .field_mut::<scale>() = 4.0;
+        changed_fields(&mut world);
+        world.get_mut::<Transform>(entity).unwrap().translation = 3.0;
+        assert_eq!((1, 1, 1, 1), changed_fields(&mut world));
+    }
+}
@@ -43,7 +43,9 @@ pub mod change_detection;
 pub mod component;
 pub mod entity;
 pub mod entity_disabling;
+pub mod entity_pool;
 pub mod event;
+pub mod field_change_detection;
 pub mod hierarchy;
 pub mod identifier;
 pub mod intern;
@@ -0,0 +1,93 @@
+use core::mem;
+
+use crate::{
+    change_detection::MaybeLocation,
+    event::Event,
+    observer::TriggerTargets,
+    resource::Resource,
+    world::{CommandQueue, World},
+};
+
+/// The triggers queued with [`World::trigger_deferred`] and [`World::trigger_targets_deferred`],
+/// which run when [`flush_deferred_triggers`] is called.
+///
+/// Unlike [`World::trigger`], which runs observers immediately and recursively runs the observers
+/// of the events they trigger, deferred triggers run in the order they were queued, at a point of
+/// the schedule chosen by adding the [`flush_deferred_triggers`] system. Once run, each trigger
+/// behaves exactly like [`World::trigger_targets`], including propagation.
+#[derive(Resource, Default)]
+pub struct DeferredTriggers {
+    queue: CommandQueue,
+}
+
+impl DeferredTriggers {
+    /// Returns `true` if no trigger is queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+/// Runs the triggers queued in [`DeferredTriggers`]. It can be added to a schedule as an
+/// exclusive system, to choose when the observers of deferred events run:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::observer::flush_deferred_triggers;
+/// #[derive(Event)]
+/// struct Hit;
+///
+/// fn attack(mut commands: Commands) {
+///     commands.trigger_deferred(Hit);
+/// }
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((attack, flush_deferred_triggers).chain());
+/// ```
+///
+/// Triggers queued while flushing, for example by observers, are only run by the next flush, so
+/// that cascading events do not run observers recursively.
+pub fn flush_deferred_triggers(world: &mut World) {
+    let Some(mut triggers) = world.get_resource_mut::<DeferredTriggers>() else {
+        return;
+    };
+    let mut queue = mem::take(&mut triggers.queue);
+    queue.apply(world);
+}
+
+impl World {
+    /// Queues the given [`Event`] in [`DeferredTriggers`], to run any [`Observer`](crate::observer::Observer)s
+    /// watching for it once [`flush_deferred_triggers`] is called.
+    #[track_caller]
+    pub fn trigger_deferred<E: Event>(&mut self, event: E) {
+        self.trigger_targets_deferred_with_caller(event, (), MaybeLocation::caller());
+    }
+
+    /// Queues the given [`Event`] in [`DeferredTriggers`] for the given `targets`, to run any
+    /// [`Observer`](crate::observer::Observer)s watching for it once [`flush_deferred_triggers`] is called.
+    #[track_caller]
+    pub fn trigger_targets_deferred<E: Event>(
+        &mut self,
+        event: E,
+        targets: impl TriggerTargets + Send + Sync + 'static,
+    ) {
+        self.trigger_targets_deferred_with_caller(event, targets, MaybeLocation::caller());
+    }
+
+    pub(crate) fn trigger_targets_deferred_with_caller<E: Event>(
+        &mut self,
+        event: E,
+        targets: impl TriggerTargets + Send + Sync + 'static,
+        caller: MaybeLocation,
+    ) {
+        self.get_resource_or_init::<DeferredTriggers>()
+            .queue
+            .push(move |world: &mut World| {
+                world.trigger_targets_with_caller(event, targets, caller);
+            });
+    }
+
+    /// Runs the triggers queued in [`DeferredTriggers`]. See [`flush_deferred_triggers`].
+    pub fn flush_deferred_triggers(&mut self) {
+        flush_deferred_triggers(self);
+    }
+}
@@ -1,11 +1,16 @@
 //! Types for creating and storing [`Observer`]s
 
+mod deferred;
 mod entity_observer;
+mod ordering;
 mod runner;
 
+pub use deferred::{flush_deferred_triggers, DeferredTriggers};
 pub use entity_observer::ObservedBy;
 pub use runner::*;
 
+use ordering::{ObserverOrder, ObserverOrdering};
+
 use crate::{
     archetype::ArchetypeFlags,
     change_detection::MaybeLocation,
@@ -364,6 +369,8 @@ pub struct CachedObservers {
     component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     entity_observers: EntityHashMap<ObserverMap>,
+    // The order in which the observers of this trigger run
+    ordering: ObserverOrdering,
 }
 
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
@@ -441,6 +448,38 @@ impl Observers {
                 propagate,
             );
         };
+        // Sort the observers if some of them are ordered
+        if let Some(ranks) = observers.ordering.ranks() {
+            let mut ordered = Vec::new();
+            let mut add_observers = |map: &ObserverMap| {
+                ordered.extend(map.iter().map(|(&observer, &runner)| {
+                    let rank = ranks.get(observer);
+                    (rank, observer, runner)
+                }));
+            };
+            add_observers(&observers.map);
+            if target != Entity::PLACEHOLDER {
+                if let Some(map) = observers.entity_observers.get(&target) {
+                    add_observers(map);
+                }
+            }
+            for id in trigger_for_components {
+                if let Some(component_observers) = observers.component_observers.get(&id) {
+                    add_observers(&component_observers.map);
+                    if target != Entity::PLACEHOLDER {
+                        if let Some(map) = component_observers.entity_map.get(&target) {
+                            add_observers(map);
+                        }
+                    }
+                }
+            }
+            ordered.sort_by_key(|(rank, ..)| *rank);
+            for (_, observer, runner) in ordered {
+                trigger_observer((&observer, &runner));
+            }
+            return;
+        }
+
         // Trigger observers listening for any kind of this trigger
         observers.map.iter().for_each(&mut trigger_observer);
 
@@ -720,6 +759,9 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache
+                .ordering
+                .insert(observer_entity, observer_state.order.clone());
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.insert(observer_entity, observer_state.runner);
@@ -764,6 +806,7 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.ordering.remove(entity);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -831,7 +874,7 @@ mod tests {
     use crate::component::ComponentId;
     use crate::{
         change_detection::MaybeLocation,
-        observer::{Observer, ObserverDescriptor, ObserverState, OnReplace},
+        observer::{DeferredTriggers, Observer, ObserverDescriptor, ObserverState, OnReplace},
         prelude::*,
         traversal::Traversal,
     };
@@ -907,6 +950,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn observer_order_constraints() {
+        #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Damage;
+
+        fn armor(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("armor");
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let entity = world.spawn_empty().id();
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("ui"))
+                .after(Damage),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("health"))
+                .with_entity(entity)
+                .in_set(Damage)
+                .after(armor),
+        );
+        world.spawn(Observer::new(armor).in_set(Damage));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("log"))
+                .with_priority(-1),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("cheat"))
+                .with_priority(10),
+        );
+        world.flush();
+
+        world.trigger_targets(EventA, entity);
+        assert_eq!(
+            vec!["cheat", "armor", "health", "ui", "log"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_order_updates() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.add_observer(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("a"));
+        let b = world
+            .spawn(
+                Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("b"))
+                    .with_priority(1),
+            )
+            .id();
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("c"))
+                .with_priority(-1),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        assert_eq!(vec!["b", "a", "c"], world.resource::<Order>().0);
+
+        // The observers are ranked again after an ordered observer is removed or added.
+        world.despawn(b);
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("d"))
+                .with_priority(-2),
+        );
+        world.flush();
+        world.resource_mut::<Order>().0.clear();
+        world.trigger(EventA);
+        assert_eq!(vec!["a", "c", "d"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_run_if() {
+        #[derive(Resource)]
+        struct Enabled(bool);
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.insert_resource(Enabled(false));
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("event_a"))
+                .run_if(|enabled: Res<Enabled>| enabled.0),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        assert!(world.resource::<Order>().0.is_empty());
+
+        world.resource_mut::<Enabled>().0 = true;
+        world.trigger(EventA);
+        assert_eq!(vec!["event_a"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_deferred_triggers() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let parent = world
+            .spawn_empty()
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("parent");
+            })
+            .id();
+        let child = world
+            .spawn(ChildOf(parent))
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("child");
+            })
+            .id();
+        world.add_observer(
+            |_: Trigger<EventA>, mut res: ResMut<Order>, mut commands: Commands| {
+                res.observed("event_a");
+                commands.trigger_deferred(EventA);
+            },
+        );
+        world.flush();
+
+        world.trigger_deferred(EventA);
+        world.trigger_targets_deferred(EventPropagating, child);
+        assert!(world.resource::<Order>().0.is_empty());
+
+        world.flush_deferred_triggers();
+        assert_eq!(
+            vec!["event_a", "child", "parent"],
+            world.resource::<Order>().0
+        );
+
+        // The trigger queued by the observer only runs with the next flush.
+        assert!(!world.resource::<DeferredTriggers>().is_empty());
+        world.flush_deferred_triggers();
+        assert_eq!(
+            vec!["event_a", "child", "parent", "event_a"],
+            world.resource::<Order>().0
+        );
+    }
+
     #[test]
     fn observer_order_insert_remove() {
         let mut world = World::new();
@@ -0,0 +1,169 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Reverse;
+
+use bevy_platform_support::{collections::HashSet, sync::OnceLock};
+
+use crate::{
+    entity::{hash_map::EntityHashMap, Entity, EntityIndexMap},
+    schedule::InternedSystemSet,
+};
+
+/// The ordering constraints of an [`Observer`](super::Observer), relative to the other observers
+/// of the same event.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct ObserverOrder {
+    pub(crate) priority: i32,
+    pub(crate) sets: Vec<InternedSystemSet>,
+    pub(crate) before: Vec<InternedSystemSet>,
+    pub(crate) after: Vec<InternedSystemSet>,
+}
+
+impl ObserverOrder {
+    fn is_unordered(&self) -> bool {
+        self.priority == 0 && self.before.is_empty() && self.after.is_empty()
+    }
+
+    /// Returns `true` if `self` must run before `other`.
+    fn runs_before(&self, other: &ObserverOrder) -> bool {
+        self.before.iter().any(|set| other.sets.contains(set))
+            || other.after.iter().any(|set| self.sets.contains(set))
+    }
+}
+
+/// The order in which the observers of an event run.
+///
+/// Observers run in registration order, unless some of them have a priority or ordering
+/// constraints, in which case these observers and the observers they are ordered against are
+/// ranked. The ranks are computed on the next trigger after the observers change.
+#[derive(Default, Debug)]
+pub(crate) struct ObserverOrdering {
+    /// The ordering constraints of every observer, in registration order.
+    observers: EntityIndexMap<ObserverOrder>,
+    /// The number of observers with a priority or ordering constraints.
+    ordered: usize,
+    ranks: OnceLock<Option<ObserverRanks>>,
+}
+
+/// The ranks of the observers of an event, see [`ObserverOrdering`].
+#[derive(Debug)]
+pub(crate) struct ObserverRanks {
+    ranks: EntityHashMap<usize>,
+    /// The rank shared by the observers which are not ranked individually.
+    unordered: usize,
+}
+
+impl ObserverRanks {
+    /// Returns the rank of `observer`, the observers with a lower rank running first.
+    pub(crate) fn get(&self, observer: Entity) -> usize {
+        self.ranks.get(&observer).copied().unwrap_or(self.unordered)
+    }
+}
+
+impl ObserverOrdering {
+    pub(crate) fn insert(&mut self, observer: Entity, order: ObserverOrder) {
+        if !order.is_unordered() {
+            self.ordered += 1;
+        }
+        if let Some(previous) = self.observers.insert(observer, order) {
+            if !previous.is_unordered() {
+                self.ordered -= 1;
+            }
+        }
+        self.ranks = OnceLock::new();
+    }
+
+    pub(crate) fn remove(&mut self, observer: Entity) {
+        if let Some(order) = self.observers.shift_remove(&observer) {
+            if !order.is_unordered() {
+                self.ordered -= 1;
+            }
+            self.ranks = OnceLock::new();
+        }
+    }
+
+    /// Returns the rank of each observer, if they have to be sorted.
+    pub(crate) fn ranks(&self) -> Option<&ObserverRanks> {
+        if self.ordered == 0 {
+            return None;
+        }
+        self.ranks.get_or_init(|| Some(self.rank())).as_ref()
+    }
+
+    /// Sorts the observers topologically, picking the observer with the highest priority among
+    /// those which can run next, and the first one registered between equal priorities.
+    ///
+    /// Only the observers with a priority or ordering constraints and the observers they are
+    /// ordered against are ranked individually. The other observers are ranked together, as if
+    /// they were a single observer with priority `0` registered last.
+    fn rank(&self) -> ObserverRanks {
+        let targets = self
+            .observers
+            .values()
+            .flat_map(|order| order.before.iter().chain(&order.after))
+            .collect::<HashSet<_>>();
+        let observers = self
+            .observers
+            .iter()
+            .filter(|(_, order)| {
+                !order.is_unordered() || order.sets.iter().any(|set| targets.contains(set))
+            })
+            .map(|(&entity, order)| (entity, order))
+            .collect::<Vec<_>>();
+
+        // The last node stands for the observers which are not ranked individually.
+        let unordered = ObserverOrder::default();
+        let count = observers.len() + 1;
+        let order = |index: usize| observers.get(index).map_or(&unordered, |&(_, order)| order);
+        let mut remaining_before = (0..count)
+            .map(|index| {
+                (0..count)
+                    .filter(|&other| other != index && order(other).runs_before(order(index)))
+                    .count()
+            })
+            .collect::<Vec<_>>();
+
+        let mut ranked = Vec::with_capacity(count);
+        let mut done = vec![false; count];
+        while ranked.len() < count {
+            let next = (0..count)
+                .filter(|&index| !done[index])
+                .filter(|&index| remaining_before[index] == 0)
+                .min_by_key(|&index| (Reverse(order(index).priority), index));
+            let next = next.unwrap_or_else(|| {
+                let cycle = (0..observers.len())
+                    .filter(|&index| !done[index])
+                    .map(|index| observers[index].0)
+                    .collect::<Vec<_>>();
+                log::error!(
+                    "The observers {cycle:?} have cyclic ordering constraints, they will run by priority instead."
+                );
+                (0..count)
+                    .filter(|&index| !done[index])
+                    .min_by_key(|&index| (Reverse(order(index).priority), index))
+                    .unwrap()
+            });
+
+            done[next] = true;
+            ranked.push(next);
+            for index in 0..count {
+                if !done[index] && order(next).runs_before(order(index)) {
+                    remaining_before[index] = remaining_before[index].saturating_sub(1);
+                }
+            }
+        }
+
+        let mut ranks = ObserverRanks {
+            ranks: EntityHashMap::default(),
+            unordered: 0,
+        };
+        for (rank, index) in ranked.into_iter().enumerate() {
+            match observers.get(index) {
+                Some(&(entity, _)) => {
+                    ranks.ranks.insert(entity, rank);
+                }
+                None => ranks.unordered = rank,
+            }
+        }
+        ranks
+    }
+}
@@ -3,10 +3,11 @@ use core::any::Any;
 
 use crate::{
     component::{ComponentHook, ComponentId, HookContext, Mutable, StorageType},
-    observer::{ObserverDescriptor, ObserverTrigger},
+    observer::{ObserverDescriptor, ObserverOrder, ObserverTrigger},
     prelude::*,
     query::DebugCheckedUnwrap,
     result::{DefaultSystemErrorHandler, SystemErrorContext},
+    schedule::{BoxedCondition, IntoSystemSet},
     system::{IntoObserverSystem, ObserverSystem},
     world::DeferredWorld,
 };
@@ -16,6 +17,7 @@ use bevy_ptr::PtrMut;
 /// "source of truth" for a given observer entity's behavior.
 pub struct ObserverState {
     pub(crate) descriptor: ObserverDescriptor,
+    pub(crate) order: ObserverOrder,
     pub(crate) runner: ObserverRunner,
     pub(crate) last_trigger_id: u32,
     pub(crate) despawned_watched_entities: u32,
@@ -28,6 +30,7 @@ impl Default for ObserverState {
             last_trigger_id: 0,
             despawned_watched_entities: 0,
             descriptor: Default::default(),
+            order: Default::default(),
         }
     }
 }
@@ -272,6 +275,8 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 pub struct Observer {
     system: Box<dyn Any + Send + Sync + 'static>,
     descriptor: ObserverDescriptor,
+    order: ObserverOrder,
+    conditions: Vec<BoxedCondition>,
     hook_on_add: ComponentHook,
     error_handler: Option<fn(Error, SystemErrorContext)>,
 }
@@ -283,6 +288,8 @@ impl Observer {
         Self {
             system: Box::new(IntoObserverSystem::into_system(system)),
             descriptor: Default::default(),
+            order: Default::default(),
+            conditions: Vec::new(),
             hook_on_add: hook_on_add::<E, B, I::System>,
             error_handler: None,
         }
@@ -327,6 +334,54 @@ impl Observer {
         self
     }
 
+    /// Adds this observer to the provided `set`, which other observers of the same event can be
+    /// ordered against with [`before`](Self::before) and [`after`](Self::after).
+    ///
+    /// Like systems, observers are also in the set of their function, so they can be ordered
+    /// against an observer function directly.
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.order.sets.push(set.intern());
+        self
+    }
+
+    /// Runs before the other observers of the same event in `set`.
+    ///
+    /// Note that this only orders the observers running for a single trigger, and that it takes
+    /// precedence over [`with_priority`](Self::with_priority).
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.order.before.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Runs after the other observers of the same event in `set`.
+    ///
+    /// Note that this only orders the observers running for a single trigger, and that it takes
+    /// precedence over [`with_priority`](Self::with_priority).
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.order.after.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Sets the priority of this observer. Among the observers of a trigger which are not ordered
+    /// by [`before`](Self::before) or [`after`](Self::after), the observers with a higher priority
+    /// run first, then the observers registered first. The default priority is `0`.
+    ///
+    /// Observers without a priority or ordering constraints run after the other observers with
+    /// priority `0`.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.order.priority = priority;
+        self
+    }
+
+    /// Only runs this observer if `condition` returns `true` when the event is triggered.
+    ///
+    /// Like for systems, multiple conditions can be added, which must all return `true`.
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        self.conditions
+            .push(Box::new(IntoSystem::into_system(condition)));
+        self
+    }
+
     /// Returns the [`ObserverDescriptor`] for this [`Observer`].
     pub fn descriptor(&self) -> &ObserverDescriptor {
         &self.descriptor
@@ -383,6 +438,25 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
             .debug_checked_unwrap()
     };
 
+    // SAFETY:
+    // - observer was triggered so must have an `Observer` component.
+    // - observer cannot be dropped or mutated until after the conditions are evaluated.
+    let conditions: *mut [BoxedCondition] = unsafe {
+        let mut observe = observer_cell.get_mut::<Observer>().debug_checked_unwrap();
+        &mut observe.as_mut().conditions[..]
+    };
+    // SAFETY:
+    // - `update_archetype_component_access` is called first
+    // - conditions are read-only, and there are no outstanding references to world except a private component
+    unsafe {
+        for condition in &mut *conditions {
+            condition.update_archetype_component_access(world);
+            if !condition.validate_param_unsafe(world) || !condition.run_unsafe((), world) {
+                return;
+            }
+        }
+    }
+
     let trigger: Trigger<E, B> = Trigger::new(
         // SAFETY: Caller ensures `ptr` is castable to `&mut T`
         unsafe { ptr.deref_mut() },
@@ -463,11 +537,24 @@ fn hook_on_add<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
             (*system).initialize(world);
         }
 
+        let mut observe = world.get_mut::<Observer>(entity).unwrap();
+        let mut order = observe.order.clone();
+        // SAFETY: `system` points to the system of the `Observer` component, which is still alive.
+        order
+            .sets
+            .extend(unsafe { (*system).default_system_sets() });
+        let mut conditions = core::mem::take(&mut observe.conditions);
+        for condition in &mut conditions {
+            condition.initialize(world);
+        }
+        world.get_mut::<Observer>(entity).unwrap().conditions = conditions;
+
         {
             let mut entity = world.entity_mut(entity);
             if let crate::world::Entry::Vacant(entry) = entity.entry::<ObserverState>() {
                 entry.insert(ObserverState {
                     descriptor,
+                    order,
                     runner: observer_system_runner::<E, B, S>,
                     ..Default::default()
                 });
@@ -0,0 +1,476 @@
+use alloc::{borrow::Cow, string::String};
+use core::{any::TypeId, ops::Deref, ptr::NonNull};
+
+use bevy_platform_support::collections::HashMap;
+use bevy_ptr::{OwningPtr, Ptr};
+use bevy_reflect::{DynamicStruct, GetField, Reflect, Struct};
+use thiserror::Error;
+
+use crate::{
+    change_detection::{DetectChanges, DetectChangesMut, MaybeLocation, Mut, Ref},
+    component::{ComponentCloneBehavior, ComponentDescriptor, ComponentId, StorageType, Tick},
+    entity::ComponentCloneCtx,
+    resource::Resource,
+    system::Commands,
+    world::{EntityRef, EntityWorldMut, FilteredEntityMut, FilteredEntityRef, World},
+};
+
+/// The schemas of the dynamic components registered with
+/// [`World::register_dynamic_component`].
+///
+/// Dynamic components are created at runtime, for example by a scripting layer, and hold a
+/// [`DynamicStruct`] instead of a Rust type. Their schema is a [`DynamicStruct`] whose fields
+/// define the names and types of the fields of the component, along with their default values.
+/// Every value inserted for the component is checked against its schema.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::QueryBuilder;
+/// # use bevy_ecs::world::FilteredEntityRef;
+/// # use bevy_reflect::{DynamicStruct, GetField};
+/// let mut world = World::new();
+///
+/// let mut schema = DynamicStruct::default();
+/// schema.insert("hp", 100i32);
+/// let health = world.register_dynamic_component("Health", schema).unwrap();
+///
+/// let entity = world.spawn_empty().insert_dynamic_default(health).unwrap().id();
+/// *world
+///     .entity_mut(entity)
+///     .get_dynamic_mut(health)
+///     .unwrap()
+///     .get_field_mut::<i32>("hp")
+///     .unwrap() -= 10;
+///
+/// let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+///     .ref_id(health)
+///     .build();
+/// for entity in query.iter(&world) {
+///     let health = entity.get_dynamic(health).unwrap();
+///     assert!(health.is_changed());
+///     assert_eq!(health.get_field::<i32>("hp"), Some(&90));
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct DynamicComponents {
+    schemas: HashMap<ComponentId, (Cow<'static, str>, DynamicStruct)>,
+    ids: HashMap<Cow<'static, str>, ComponentId>,
+}
+
+impl DynamicComponents {
+    /// Returns the [`ComponentId`] of the dynamic component with the given `name`.
+    pub fn get_id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the schema of the given dynamic component.
+    pub fn schema(&self, id: ComponentId) -> Option<&DynamicStruct> {
+        self.schemas.get(&id).map(|(_, schema)| schema)
+    }
+
+    /// Returns the name and [`ComponentId`] of every dynamic component.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, ComponentId)> + '_ {
+        self.ids.iter().map(|(name, id)| (&**name, *id))
+    }
+
+    /// Checks that `value` has the fields of the schema of the dynamic component `id`, with the
+    /// same types.
+    pub fn validate(
+        &self,
+        id: ComponentId,
+        value: &DynamicStruct,
+    ) -> Result<(), DynamicComponentError> {
+        let Some((name, schema)) = self.schemas.get(&id) else {
+            return Err(DynamicComponentError::NotDynamic(id));
+        };
+        for (index, expected) in schema.iter_fields().enumerate() {
+            let field = schema.name_at(index).unwrap_or_default();
+            let Some(found) = value.field(field) else {
+                return Err(DynamicComponentError::MissingField {
+                    component: name.clone().into_owned(),
+                    field: field.into(),
+                });
+            };
+            if found.reflect_type_path() != expected.reflect_type_path() {
+                return Err(DynamicComponentError::FieldType {
+                    component: name.clone().into_owned(),
+                    field: field.into(),
+                    expected: expected.reflect_type_path().into(),
+                    found: found.reflect_type_path().into(),
+                });
+            }
+        }
+        if let Some(field) = (0..value.field_len())
+            .filter_map(|index| value.name_at(index))
+            .find(|field| schema.field(field).is_none())
+        {
+            return Err(DynamicComponentError::UnknownField {
+                component: name.clone().into_owned(),
+                field: field.into(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// An error when registering or inserting a dynamic component. See [`DynamicComponents`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DynamicComponentError {
+    /// A dynamic component with the same name is already registered.
+    #[error("A dynamic component named {0} is already registered")]
+    NameTaken(String),
+    /// The component was not registered with [`World::register_dynamic_component`].
+    #[error("The component {0:?} is not a dynamic component")]
+    NotDynamic(ComponentId),
+    /// A field of the schema is missing from the value.
+    #[error("The value of the dynamic component {component} has no {field} field")]
+    MissingField {
+        /// The name of the component.
+        component: String,
+        /// The name of the missing field.
+        field: String,
+    },
+    /// The value has a field which is not in the schema.
+    #[error("The value of the dynamic component {component} has an unknown {field} field")]
+    UnknownField {
+        /// The name of the component.
+        component: String,
+        /// The name of the unknown field.
+        field: String,
+    },
+    /// A field of the value does not have the type of the schema.
+    #[error("The field {field} of the dynamic component {component} should be a {expected}, not a {found}")]
+    FieldType {
+        /// The name of the component.
+        component: String,
+        /// The name of the field.
+        field: String,
+        /// The type path of the field in the schema.
+        expected: String,
+        /// The type path of the field in the value.
+        found: String,
+    },
+}
+
+/// Mutable access to the value of a dynamic component, returned by
+/// [`EntityWorldMut::get_dynamic_mut`].
+///
+/// Only the fields of the value can be modified, in place and keeping their type, so that the
+/// value keeps matching the schema of the component. To replace the whole value, insert it again
+/// with [`EntityWorldMut::insert_dynamic`], which checks it against the schema.
+pub struct DynamicComponentMut<'w> {
+    value: Mut<'w, DynamicStruct>,
+}
+
+impl<'w> DynamicComponentMut<'w> {
+    /// Returns a mutable reference to the field `name`, if it has the type `T`.
+    ///
+    /// The component is only marked as changed if the field is found.
+    pub fn get_field_mut<T: Reflect>(&mut self, name: &str) -> Option<&mut T> {
+        self.value.get_field::<T>(name)?;
+        self.value.get_field_mut::<T>(name)
+    }
+
+    /// Marks the component as changed.
+    pub fn set_changed(&mut self) {
+        self.value.set_changed();
+    }
+}
+
+impl<'w> Deref for DynamicComponentMut<'w> {
+    type Target = DynamicStruct;
+
+    fn deref(&self) -> &DynamicStruct {
+        &self.value
+    }
+}
+
+impl<'w> DetectChanges for DynamicComponentMut<'w> {
+    fn is_added(&self) -> bool {
+        self.value.is_added()
+    }
+
+    fn is_changed(&self) -> bool {
+        self.value.is_changed()
+    }
+
+    fn last_changed(&self) -> Tick {
+        self.value.last_changed()
+    }
+
+    fn changed_by(&self) -> MaybeLocation {
+        self.value.changed_by()
+    }
+}
+
+fn clone_dynamic_component(_commands: &mut Commands, ctx: &mut ComponentCloneCtx) {
+    // SAFETY: dynamic components hold a `DynamicStruct`, and the target is written with one.
+    unsafe {
+        ctx.write_target_component_ptr(|source: Ptr, target: NonNull<u8>| {
+            let value = source.deref::<DynamicStruct>().clone_dynamic();
+            target.cast::<DynamicStruct>().write(value);
+            true
+        });
+    }
+}
+
+impl World {
+    /// Registers a dynamic component named `name`, whose fields are the fields of `schema`.
+    /// See [`DynamicComponents`].
+    pub fn register_dynamic_component(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        schema: DynamicStruct,
+    ) -> Result<ComponentId, DynamicComponentError> {
+        let name = name.into();
+        if self
+            .get_resource::<DynamicComponents>()
+            .is_some_and(|components| components.ids.contains_key(&name))
+        {
+            return Err(DynamicComponentError::NameTaken(name.into_owned()));
+        }
+        let id = self.register_component_with_descriptor(ComponentDescriptor::new_runtime::<
+            DynamicStruct,
+        >(
+            name.clone(),
+            StorageType::Table,
+            ComponentCloneBehavior::Custom(clone_dynamic_component),
+        ));
+        let mut components = self.get_resource_or_init::<DynamicComponents>();
+        components.ids.insert(name.clone(), id);
+        components.schemas.insert(id, (name, schema));
+        Ok(id)
+    }
+
+    /// Returns the [`ComponentId`] of the dynamic component with the given `name`.
+    pub fn dynamic_component_id(&self, name: &str) -> Option<ComponentId> {
+        self.get_resource::<DynamicComponents>()?.get_id(name)
+    }
+}
+
+fn is_dynamic(world: &World, id: ComponentId) -> bool {
+    world
+        .components()
+        .get_info(id)
+        .is_some_and(|info| info.type_id() == Some(TypeId::of::<DynamicStruct>()))
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Inserts the dynamic component `id` with the given `value`, after checking it against its
+    /// schema. See [`DynamicComponents`].
+    pub fn insert_dynamic(
+        &mut self,
+        id: ComponentId,
+        value: DynamicStruct,
+    ) -> Result<&mut Self, DynamicComponentError> {
+        self.world()
+            .get_resource::<DynamicComponents>()
+            .ok_or(DynamicComponentError::NotDynamic(id))?
+            .validate(id, &value)?;
+        // The schemas may have been moved from another world, where `id` is another component.
+        if !is_dynamic(self.world(), id) {
+            return Err(DynamicComponentError::NotDynamic(id));
+        }
+        OwningPtr::make(value, |ptr| {
+            // SAFETY: `id` holds a `DynamicStruct`, as checked above.
+            unsafe {
+                self.insert_by_id(id, ptr);
+            }
+        });
+        Ok(self)
+    }
+
+    /// Inserts the dynamic component `id`, with the values of its schema.
+    pub fn insert_dynamic_default(
+        &mut self,
+        id: ComponentId,
+    ) -> Result<&mut Self, DynamicComponentError> {
+        let value = self
+            .world()
+            .get_resource::<DynamicComponents>()
+            .and_then(|components| components.schema(id))
+            .ok_or(DynamicComponentError::NotDynamic(id))?
+            .clone_dynamic();
+        self.insert_dynamic(id, value)
+    }
+
+    /// Gets the value of the dynamic component `id`, including change detection information.
+    ///
+    /// Returns `None` if the entity does not have the component, or if it is not a dynamic
+    /// component.
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<Ref<'_, DynamicStruct>> {
+        self.get_typed_ref_by_id(id)
+    }
+
+    /// Gets mutable access to the fields of the dynamic component `id`. See
+    /// [`DynamicComponentMut`].
+    ///
+    /// Returns `None` if the entity does not have the component, or if it is not a dynamic
+    /// component.
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<DynamicComponentMut<'_>> {
+        self.get_typed_mut_by_id(id)
+            .map(|value| DynamicComponentMut { value })
+    }
+}
+
+impl<'w> EntityRef<'w> {
+    /// Gets the value of the dynamic component `id`, including change detection information.
+    /// See [`EntityWorldMut::get_dynamic`].
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<Ref<'w, DynamicStruct>> {
+        self.get_typed_ref_by_id(id)
+    }
+}
+
+impl<'w> FilteredEntityRef<'w> {
+    /// Gets the value of the dynamic component `id`, including change detection information.
+    /// See [`EntityWorldMut::get_dynamic`].
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<Ref<'w, DynamicStruct>> {
+        self.get_typed_ref_by_id(id)
+    }
+}
+
+impl<'w> FilteredEntityMut<'w> {
+    /// Gets the value of the dynamic component `id`, including change detection information.
+    /// See [`EntityWorldMut::get_dynamic`].
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<Ref<'_, DynamicStruct>> {
+        self.get_typed_ref_by_id(id)
+    }
+
+    /// Gets mutable access to the fields of the dynamic component `id`.
+    /// See [`EntityWorldMut::get_dynamic_mut`].
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<DynamicComponentMut<'_>> {
+        self.get_typed_mut_by_id(id)
+            .map(|value| DynamicComponentMut { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamicComponentError, DynamicComponents};
+    use crate::{
+        change_detection::DetectChanges,
+        component::Component,
+        query::QueryBuilder,
+        world::{FilteredEntityMut, World},
+    };
+    use alloc::string::String;
+    use bevy_reflect::{DynamicStruct, GetField, Struct};
+
+    #[derive(Component)]
+    struct Marker;
+
+    fn schema() -> DynamicStruct {
+        let mut schema = DynamicStruct::default();
+        schema.insert("hp", 100i32);
+        schema.insert("name", String::from("goblin"));
+        schema
+    }
+
+    #[test]
+    fn dynamic_component_validation() {
+        let mut world = World::new();
+        let health = world
+            .register_dynamic_component("Health", schema())
+            .unwrap();
+        assert_eq!(world.dynamic_component_id("Health"), Some(health));
+        assert_eq!(
+            world.register_dynamic_component("Health", schema()),
+            Err(DynamicComponentError::NameTaken("Health".into()))
+        );
+
+        let mut entity = world.spawn_empty();
+        let mut value = DynamicStruct::default();
+        value.insert("hp", 10i32);
+        assert!(matches!(
+            entity.insert_dynamic(health, value.clone_dynamic()),
+            Err(DynamicComponentError::MissingField { field, .. }) if field == "name"
+        ));
+        value.insert("name", 3u8);
+        assert!(matches!(
+            entity.insert_dynamic(health, value.clone_dynamic()),
+            Err(DynamicComponentError::FieldType { field, .. }) if field == "name"
+        ));
+        value.insert("name", String::from("orc"));
+        value.insert("armor", 1i32);
+        assert!(matches!(
+            entity.insert_dynamic(health, value.clone_dynamic()),
+            Err(DynamicComponentError::UnknownField { field, .. }) if field == "armor"
+        ));
+
+        let marker = world.register_component::<Marker>();
+        let mut entity = world.spawn(Marker);
+        assert_eq!(
+            entity.insert_dynamic_default(marker).err(),
+            Some(DynamicComponentError::NotDynamic(marker))
+        );
+        assert!(entity.get_dynamic(marker).is_none());
+    }
+
+    #[test]
+    fn dynamic_components_of_another_world() {
+        let mut world = World::new();
+        let health = world
+            .register_dynamic_component("Health", schema())
+            .unwrap();
+        let components = world.remove_resource::<DynamicComponents>().unwrap();
+
+        // In another world, the same id belongs to a component of another type.
+        let mut other = World::new();
+        let marker = other.register_component::<Marker>();
+        assert_eq!(marker, health);
+        other.insert_resource(components);
+
+        let mut entity = other.spawn_empty();
+        assert_eq!(
+            entity.insert_dynamic_default(health).err(),
+            Some(DynamicComponentError::NotDynamic(health))
+        );
+        assert!(!entity.contains_id(health));
+    }
+
+    #[test]
+    fn dynamic_component_change_detection() {
+        let mut world = World::new();
+        let health = world
+            .register_dynamic_component("Health", schema())
+            .unwrap();
+        let entity = world
+            .spawn_empty()
+            .insert_dynamic_default(health)
+            .unwrap()
+            .id();
+        let clone = world.entity_mut(entity).clone_and_spawn();
+        assert_eq!(
+            world
+                .entity(clone)
+                .get_dynamic(health)
+                .unwrap()
+                .get_field::<String>("name"),
+            Some(&String::from("goblin"))
+        );
+
+        let last_run = world.change_tick();
+        world.increment_change_tick();
+        let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+            .mut_id(health)
+            .build();
+        for mut found in query.iter_mut(&mut world) {
+            if found.id() == entity {
+                let mut hp = found.get_dynamic_mut(health).unwrap();
+                // A field can only be modified with its own type.
+                let last_changed = hp.last_changed();
+                assert!(hp.get_field_mut::<u32>("hp").is_none());
+                assert_eq!(hp.last_changed(), last_changed);
+                *hp.get_field_mut::<i32>("hp").unwrap() -= 30;
+            }
+        }
+
+        let this_run = world.change_tick();
+        let hp = world.entity(entity).get_dynamic(health).unwrap();
+        assert!(hp.last_changed().is_newer_than(last_run, this_run));
+        assert_eq!(hp.get_field::<i32>("hp"), Some(&70));
+        let hp = world.entity(clone).get_dynamic(health).unwrap();
+        assert!(!hp.last_changed().is_newer_than(last_run, this_run));
+        assert_eq!(hp.get_field::<i32>("hp"), Some(&100));
+    }
+}
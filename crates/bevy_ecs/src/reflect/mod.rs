@@ -13,6 +13,7 @@ use bevy_reflect::{
 
 mod bundle;
 mod component;
+mod dynamic_component;
 mod entity_commands;
 mod from_world;
 mod map_entities;
@@ -21,6 +22,7 @@ mod visit_entities;
 
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
+pub use dynamic_component::{DynamicComponentError, DynamicComponentMut, DynamicComponents};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::ReflectMapEntities;
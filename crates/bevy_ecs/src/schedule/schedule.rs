@@ -44,6 +44,9 @@ pub struct Schedules {
     inner: HashMap<InternedScheduleLabel, Schedule>,
     /// List of [`ComponentId`]s to ignore when reporting system order ambiguity conflicts
     pub ignored_scheduling_ambiguities: BTreeSet<ComponentId>,
+    /// If set, every schedule is switched to this [`ExecutorKind`] when it is rebuilt, before it
+    /// runs with new systems, overriding [`Schedule::set_executor_kind`].
+    pub forced_executor_kind: Option<ExecutorKind>,
 }
 
 impl Schedules {
@@ -472,10 +475,11 @@ impl Schedule {
     pub fn initialize(&mut self, world: &mut World) -> Result<(), ScheduleBuildError> {
         if self.graph.changed {
             self.graph.initialize(world);
-            let ignored_ambiguities = world
-                .get_resource_or_init::<Schedules>()
-                .ignored_scheduling_ambiguities
-                .clone();
+            let schedules = world.get_resource_or_init::<Schedules>();
+            let ignored_ambiguities = schedules.ignored_scheduling_ambiguities.clone();
+            if let Some(executor) = schedules.forced_executor_kind {
+                self.set_executor_kind(executor);
+            }
             self.graph.update_schedule(
                 world,
                 &mut self.executable,
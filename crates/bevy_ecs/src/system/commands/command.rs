@@ -265,6 +265,29 @@ pub fn trigger_targets(
     }
 }
 
+/// A [`Command`] that queues a global [`Trigger`](crate::observer::Trigger) without any targets,
+/// which runs once [`flush_deferred_triggers`](crate::observer::flush_deferred_triggers) is called.
+#[track_caller]
+pub fn trigger_deferred(event: impl Event) -> impl Command {
+    let caller = MaybeLocation::caller();
+    move |world: &mut World| {
+        world.trigger_targets_deferred_with_caller(event, (), caller);
+    }
+}
+
+/// A [`Command`] that queues a [`Trigger`](crate::observer::Trigger) for the given targets, which
+/// runs once [`flush_deferred_triggers`](crate::observer::flush_deferred_triggers) is called.
+#[track_caller]
+pub fn trigger_targets_deferred(
+    event: impl Event,
+    targets: impl TriggerTargets + Send + Sync + 'static,
+) -> impl Command {
+    let caller = MaybeLocation::caller();
+    move |world: &mut World| {
+        world.trigger_targets_deferred_with_caller(event, targets, caller);
+    }
+}
+
 /// A [`Command`] that sends an arbitrary [`Event`].
 #[track_caller]
 pub fn send_event<E: Event>(event: E) -> impl Command {
@@ -1057,6 +1057,32 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue(command::trigger_targets(event, targets));
     }
 
+    /// Queues a "global" [`Trigger`] without any targets in [`DeferredTriggers`], which runs once
+    /// [`flush_deferred_triggers`] is called rather than when the commands are applied.
+    ///
+    /// [`Trigger`]: crate::observer::Trigger
+    /// [`DeferredTriggers`]: crate::observer::DeferredTriggers
+    /// [`flush_deferred_triggers`]: crate::observer::flush_deferred_triggers
+    #[track_caller]
+    pub fn trigger_deferred(&mut self, event: impl Event) {
+        self.queue(command::trigger_deferred(event));
+    }
+
+    /// Queues a [`Trigger`] for the given targets in [`DeferredTriggers`], which runs once
+    /// [`flush_deferred_triggers`] is called rather than when the commands are applied.
+    ///
+    /// [`Trigger`]: crate::observer::Trigger
+    /// [`DeferredTriggers`]: crate::observer::DeferredTriggers
+    /// [`flush_deferred_triggers`]: crate::observer::flush_deferred_triggers
+    #[track_caller]
+    pub fn trigger_targets_deferred(
+        &mut self,
+        event: impl Event,
+        targets: impl TriggerTargets + Send + Sync + 'static,
+    ) {
+        self.queue(command::trigger_targets_deferred(event, targets));
+    }
+
     /// Spawns an [`Observer`] and returns the [`EntityCommands`] associated
     /// with the entity that stores the observer.
     ///
@@ -161,6 +161,18 @@ impl<'w> EntityRef<'w> {
         unsafe { self.cell.get_change_ticks_by_id(component_id) }
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Ref`] of type `T`, if the component
+    /// holds values of type `T`.
+    #[inline]
+    pub(crate) fn get_typed_ref_by_id<T: 'static>(
+        &self,
+        component_id: ComponentId,
+    ) -> Option<Ref<'w, T>> {
+        // SAFETY: We have read-only access to all components of this entity.
+        unsafe { self.cell.get_typed_ref_by_id(component_id) }
+    }
+
     /// Returns [untyped read-only reference(s)](Ptr) to component(s) for the
     /// current entity, based on the given [`ComponentId`]s.
     ///
@@ -1387,6 +1399,32 @@ impl<'w> EntityWorldMut<'w> {
         self.as_readonly().get_change_ticks_by_id(component_id)
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Ref`] of type `T`, if the component
+    /// holds values of type `T`.
+    #[inline]
+    pub(crate) fn get_typed_ref_by_id<T: 'static>(
+        &self,
+        component_id: ComponentId,
+    ) -> Option<Ref<'_, T>> {
+        self.as_readonly().get_typed_ref_by_id(component_id)
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Mut`] of type `T`, if the component
+    /// holds values of type `T`.
+    #[inline]
+    pub(crate) fn get_typed_mut_by_id<T: 'static>(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Option<Mut<'_, T>> {
+        // SAFETY: We have exclusive access to the entity.
+        unsafe {
+            self.as_unsafe_entity_cell()
+                .get_typed_mut_by_id(component_id)
+        }
+    }
+
     /// Returns [untyped read-only reference(s)](Ptr) to component(s) for the
     /// current entity, based on the given [`ComponentId`]s.
     ///
@@ -3174,6 +3212,21 @@ impl<'w> FilteredEntityRef<'w> {
             .flatten()
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Ref`] of type `T`, if the component
+    /// holds values of type `T`.
+    #[inline]
+    pub(crate) fn get_typed_ref_by_id<T: 'static>(
+        &self,
+        component_id: ComponentId,
+    ) -> Option<Ref<'w, T>> {
+        self.access
+            .has_component_read(component_id)
+            // SAFETY: We have read access
+            .then(|| unsafe { self.entity.get_typed_ref_by_id(component_id) })
+            .flatten()
+    }
+
     /// Gets the component of the given [`ComponentId`] from the entity.
     ///
     /// **You should prefer to use the typed API [`Self::get`] where possible and only
@@ -3542,6 +3595,32 @@ impl<'w> FilteredEntityMut<'w> {
         self.as_readonly().get_change_ticks_by_id(component_id)
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Ref`] of type `T`, if the component
+    /// holds values of type `T`.
+    #[inline]
+    pub(crate) fn get_typed_ref_by_id<T: 'static>(
+        &self,
+        component_id: ComponentId,
+    ) -> Option<Ref<'_, T>> {
+        self.as_readonly().get_typed_ref_by_id(component_id)
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Mut`] of type `T`, if the component
+    /// holds values of type `T`.
+    #[inline]
+    pub(crate) fn get_typed_mut_by_id<T: 'static>(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Option<Mut<'_, T>> {
+        self.access
+            .has_component_write(component_id)
+            // SAFETY: We have write access
+            .then(|| unsafe { self.entity.get_typed_mut_by_id(component_id) })
+            .flatten()
+    }
+
     /// Gets the component of the given [`ComponentId`] from the entity.
     ///
     /// **You should prefer to use the typed API [`Self::get`] where possible and only
@@ -824,6 +824,72 @@ impl<'w> UnsafeEntityCell<'w> {
         }
     }
 
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Ref`] of type `T`, including change
+    /// detection information. Returns `None` if the entity does not have the component, or if
+    /// the component does not hold values of type `T`.
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeEntityCell`] has permission to access the component
+    /// - no other mutable references to the component exist at the same time
+    #[inline]
+    pub(crate) unsafe fn get_typed_ref_by_id<T: 'static>(
+        self,
+        component_id: ComponentId,
+    ) -> Option<Ref<'w, T>> {
+        let last_change_tick = self.world.last_change_tick();
+        let change_tick = self.world.change_tick();
+        let info = self.world.components().get_info(component_id)?;
+        if info.type_id() != Some(TypeId::of::<T>()) {
+            return None;
+        }
+
+        // SAFETY:
+        // - `storage_type` is correct
+        // - `location` is valid
+        // - proper aliasing is promised by caller
+        unsafe {
+            get_component_and_ticks(
+                self.world,
+                component_id,
+                info.storage_type(),
+                self.entity,
+                self.location,
+            )
+            .map(|(value, cells, caller)| Ref {
+                // SAFETY: the component holds values of type T, as checked above
+                value: value.deref::<T>(),
+                ticks: Ticks::from_tick_cells(cells, last_change_tick, change_tick),
+                changed_by: caller.map(|caller| caller.deref()),
+            })
+        }
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    /// Gets the component of the given [`ComponentId`] as a [`Mut`] of type `T`. Returns `None` if
+    /// the entity does not have the component, or if the component does not hold values of
+    /// type `T`.
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeEntityCell`] has permission to access the component mutably
+    /// - no other references to the component exist at the same time
+    #[inline]
+    pub(crate) unsafe fn get_typed_mut_by_id<T: 'static>(
+        self,
+        component_id: ComponentId,
+    ) -> Option<Mut<'w, T>> {
+        let info = self.world.components().get_info(component_id)?;
+        if info.type_id() != Some(TypeId::of::<T>()) {
+            return None;
+        }
+        // SAFETY:
+        // - proper aliasing is promised by caller
+        // - the component holds values of type T, as checked above
+        unsafe { Some(self.get_mut_by_id(component_id).ok()?.with_type::<T>()) }
+    }
+
     /// Retrieves the change ticks for the given component. This can be useful for implementing change
     /// detection in custom runtimes.
     ///
@@ -0,0 +1,377 @@
+use crate::{Image, ImageFormat, ImageFormatSetting, ImageLoader, ImageLoaderSettings};
+
+use bevy_asset::saver::{AssetSaver, SavedAsset};
+use futures_lite::AsyncWriteExt;
+use thiserror::Error;
+use wgpu_types::TextureFormat;
+#[cfg(feature = "ktx2")]
+use {
+    crate::TextureFormatPixelInfo,
+    wgpu_types::{TextureDimension, TextureViewDimension},
+};
+
+/// Saves an [`Image`] as a PNG file, which can be loaded back with the [`ImageLoader`].
+///
+/// Only the first mip level and layer of the image are saved. The supported texture formats are the ones supported by
+/// [`Image::try_into_dynamic`].
+#[cfg(feature = "png")]
+pub struct PngImageSaver;
+
+/// Saves an [`Image`] as an uncompressed KTX2 file, which can be loaded back with the [`ImageLoader`].
+///
+/// All mip levels, array layers and cube faces of the image are saved. Only the following texture formats are
+/// supported:
+/// - `TextureFormat::R8Unorm`
+/// - `TextureFormat::Rg8Unorm`
+/// - `TextureFormat::Rgba8Unorm`
+/// - `TextureFormat::Rgba8UnormSrgb`
+/// - `TextureFormat::R16Float`
+/// - `TextureFormat::Rgba16Float`
+/// - `TextureFormat::R32Float`
+/// - `TextureFormat::Rgba32Float`
+#[cfg(feature = "ktx2")]
+pub struct Ktx2ImageSaver;
+
+/// An error when saving an [`Image`] with one of the image savers.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ImageSaverError {
+    /// An [IO](std::io) error occurred while writing the image.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The image has no data to save.
+    #[error("Cannot save an uninitialized image")]
+    UninitializedImage,
+    /// The [`TextureFormat`] of the image is not supported by the saver.
+    #[error("Cannot save an image with the texture format {0:?}")]
+    UnsupportedTextureFormat(TextureFormat),
+    /// The image could not be converted to a [`DynamicImage`](image::DynamicImage).
+    #[cfg(feature = "png")]
+    #[error(transparent)]
+    IntoDynamicImage(#[from] crate::IntoDynamicImageError),
+    /// The image could not be encoded.
+    #[cfg(feature = "png")]
+    #[error("Failed to encode the image: {0}")]
+    Encode(#[from] image::ImageError),
+}
+
+#[cfg(feature = "png")]
+impl AssetSaver for PngImageSaver {
+    type Asset = Image;
+
+    type Settings = ();
+    type OutputLoader = ImageLoader;
+    type Error = ImageSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut bevy_asset::io::Writer,
+        image: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<ImageLoaderSettings, Self::Error> {
+        let mut png_data = Vec::new();
+        image.clone().try_into_dynamic()?.write_to(
+            &mut std::io::Cursor::new(&mut png_data),
+            image::ImageFormat::Png,
+        )?;
+
+        writer.write_all(&png_data).await?;
+        Ok(ImageLoaderSettings {
+            format: ImageFormatSetting::Format(ImageFormat::Png),
+            is_srgb: image.texture_descriptor.format.is_srgb(),
+            sampler: image.sampler.clone(),
+            asset_usage: image.asset_usage,
+        })
+    }
+}
+
+#[cfg(feature = "ktx2")]
+impl AssetSaver for Ktx2ImageSaver {
+    type Asset = Image;
+
+    type Settings = ();
+    type OutputLoader = ImageLoader;
+    type Error = ImageSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut bevy_asset::io::Writer,
+        image: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<ImageLoaderSettings, Self::Error> {
+        let ktx2_data = image_to_ktx2(&image)?;
+
+        writer.write_all(&ktx2_data).await?;
+        Ok(ImageLoaderSettings {
+            format: ImageFormatSetting::Format(ImageFormat::Ktx2),
+            is_srgb: image.texture_descriptor.format.is_srgb(),
+            sampler: image.sampler.clone(),
+            asset_usage: image.asset_usage,
+        })
+    }
+}
+
+/// The channels of a supported uncompressed texture format, as stored in a KTX2 data format descriptor.
+#[cfg(feature = "ktx2")]
+struct Ktx2FormatInfo {
+    format: ktx2::Format,
+    channels: &'static [u8],
+    bits_per_channel: u8,
+    is_float: bool,
+}
+
+#[cfg(feature = "ktx2")]
+fn ktx2_format_info(format: TextureFormat) -> Option<Ktx2FormatInfo> {
+    const R: &[u8] = &[0];
+    const RG: &[u8] = &[0, 1];
+    const RGBA: &[u8] = &[0, 1, 2, 15];
+    let (format, channels, bits_per_channel, is_float) = match format {
+        TextureFormat::R8Unorm => (ktx2::Format::R8_UNORM, R, 8, false),
+        TextureFormat::Rg8Unorm => (ktx2::Format::R8G8_UNORM, RG, 8, false),
+        TextureFormat::Rgba8Unorm => (ktx2::Format::R8G8B8A8_UNORM, RGBA, 8, false),
+        TextureFormat::Rgba8UnormSrgb => (ktx2::Format::R8G8B8A8_SRGB, RGBA, 8, false),
+        TextureFormat::R16Float => (ktx2::Format::R16_SFLOAT, R, 16, true),
+        TextureFormat::Rgba16Float => (ktx2::Format::R16G16B16A16_SFLOAT, RGBA, 16, true),
+        TextureFormat::R32Float => (ktx2::Format::R32_SFLOAT, R, 32, true),
+        TextureFormat::Rgba32Float => (ktx2::Format::R32G32B32A32_SFLOAT, RGBA, 32, true),
+        _ => return None,
+    };
+    Some(Ktx2FormatInfo {
+        format,
+        channels,
+        bits_per_channel,
+        is_float,
+    })
+}
+
+/// Builds the basic data format descriptor block for an uncompressed texture format.
+#[cfg(feature = "ktx2")]
+fn ktx2_data_format_descriptor(info: &Ktx2FormatInfo, is_srgb: bool) -> Vec<u8> {
+    const COLOR_MODEL_RGBSDA: u8 = 1;
+    const COLOR_PRIMARIES_BT709: u8 = 1;
+    const TRANSFER_FUNCTION_LINEAR: u8 = 1;
+    const TRANSFER_FUNCTION_SRGB: u8 = 2;
+    const CHANNEL_ALPHA: u8 = 15;
+    const QUALIFIER_LINEAR: u8 = 1 << 4;
+    const QUALIFIER_SIGNED: u8 = 1 << 6;
+    const QUALIFIER_FLOAT: u8 = 1 << 7;
+
+    let block_size = 24 + 16 * info.channels.len() as u16;
+    let texel_bytes = info.channels.len() as u8 * info.bits_per_channel / 8;
+
+    let mut dfd = Vec::new();
+    dfd.extend_from_slice(&(4 + block_size as u32).to_le_bytes());
+    // Khronos vendor id and basic descriptor type.
+    dfd.extend_from_slice(&0u32.to_le_bytes());
+    dfd.extend_from_slice(&2u16.to_le_bytes());
+    dfd.extend_from_slice(&block_size.to_le_bytes());
+    dfd.extend_from_slice(&[
+        COLOR_MODEL_RGBSDA,
+        COLOR_PRIMARIES_BT709,
+        if is_srgb {
+            TRANSFER_FUNCTION_SRGB
+        } else {
+            TRANSFER_FUNCTION_LINEAR
+        },
+        0,
+    ]);
+    // Uncompressed formats have a 1x1x1x1 texel block, stored as dimension minus one.
+    dfd.extend_from_slice(&[0; 4]);
+    dfd.extend_from_slice(&[texel_bytes, 0, 0, 0, 0, 0, 0, 0]);
+    for (index, &channel) in info.channels.iter().enumerate() {
+        let mut channel_type = channel;
+        let (lower, upper) = if info.is_float {
+            channel_type |= QUALIFIER_FLOAT | QUALIFIER_SIGNED;
+            ((-1.0f32).to_bits(), 1.0f32.to_bits())
+        } else {
+            (0, u32::MAX >> (32 - info.bits_per_channel))
+        };
+        if is_srgb && channel == CHANNEL_ALPHA {
+            channel_type |= QUALIFIER_LINEAR;
+        }
+        dfd.extend_from_slice(&(index as u16 * info.bits_per_channel as u16).to_le_bytes());
+        dfd.extend_from_slice(&[info.bits_per_channel - 1, channel_type]);
+        dfd.extend_from_slice(&[0; 4]);
+        dfd.extend_from_slice(&lower.to_le_bytes());
+        dfd.extend_from_slice(&upper.to_le_bytes());
+    }
+    dfd
+}
+
+/// Encodes `image` as an uncompressed KTX2 file.
+#[cfg(feature = "ktx2")]
+fn image_to_ktx2(image: &Image) -> Result<Vec<u8>, ImageSaverError> {
+    const KTX2_MAGIC: [u8; 12] = [
+        0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+    ];
+    const HEADER_LENGTH: usize = 80;
+    const LEVEL_INDEX_LENGTH: usize = 24;
+
+    let texture_format = image.texture_descriptor.format;
+    let info = ktx2_format_info(texture_format)
+        .ok_or(ImageSaverError::UnsupportedTextureFormat(texture_format))?;
+    let Some(data) = image.data.as_ref() else {
+        return Err(ImageSaverError::UninitializedImage);
+    };
+
+    let size = image.texture_descriptor.size;
+    let level_count = image.texture_descriptor.mip_level_count.max(1);
+    let is_cube = matches!(
+        image
+            .texture_view_descriptor
+            .as_ref()
+            .and_then(|descriptor| descriptor.dimension),
+        Some(TextureViewDimension::Cube | TextureViewDimension::CubeArray)
+    );
+    let (depth, layer_count, face_count) =
+        if image.texture_descriptor.dimension == TextureDimension::D3 {
+            (size.depth_or_array_layers, 1, 1)
+        } else if is_cube {
+            (1, size.depth_or_array_layers / 6, 6)
+        } else {
+            (1, size.depth_or_array_layers, 1)
+        };
+
+    // Reorder data from wgpu LayerYFaceZMipX to KTX2 MipXLayerYFaceZ
+    let texel_bytes = texture_format.pixel_size();
+    let mut levels = vec![Vec::new(); level_count as usize];
+    let mut offset = 0;
+    for _ in 0..layer_count * face_count {
+        for (level, level_data) in levels.iter_mut().enumerate() {
+            let level_bytes = (size.width as usize >> level).max(1)
+                * (size.height as usize >> level).max(1)
+                * (depth as usize >> level).max(1)
+                * texel_bytes;
+            let level_slice = data
+                .get(offset..offset + level_bytes)
+                .ok_or(ImageSaverError::UninitializedImage)?;
+            level_data.extend_from_slice(level_slice);
+            offset += level_bytes;
+        }
+    }
+
+    let dfd = ktx2_data_format_descriptor(&info, texture_format.is_srgb());
+    let dfd_offset = HEADER_LENGTH + LEVEL_INDEX_LENGTH * levels.len();
+    // Mip levels are stored from the smallest to the largest, each aligned to the texel size and 4 bytes.
+    let alignment = texel_bytes.max(4);
+    let mut level_index = vec![(0u64, 0u64); levels.len()];
+    let mut level_data = Vec::new();
+    let mut data_offset = dfd_offset + dfd.len();
+    for (level, data) in levels.iter().enumerate().rev() {
+        let padding = data_offset.next_multiple_of(alignment) - data_offset;
+        level_data.resize(level_data.len() + padding, 0);
+        data_offset += padding;
+        level_index[level] = (data_offset as u64, data.len() as u64);
+        level_data.extend_from_slice(data);
+        data_offset += data.len();
+    }
+
+    let mut ktx2_data = Vec::with_capacity(data_offset);
+    ktx2_data.extend_from_slice(&KTX2_MAGIC);
+    for value in [
+        info.format.0.get(),
+        // Type size, used for endianness conversion.
+        info.bits_per_channel as u32 / 8,
+        size.width,
+        size.height,
+        if depth > 1 { depth } else { 0 },
+        if layer_count > 1 { layer_count } else { 0 },
+        face_count,
+        level_count,
+        // No supercompression.
+        0,
+        dfd_offset as u32,
+        dfd.len() as u32,
+        // No key/value data.
+        0,
+        0,
+    ] {
+        ktx2_data.extend_from_slice(&value.to_le_bytes());
+    }
+    // No supercompression global data.
+    ktx2_data.extend_from_slice(&[0; 16]);
+    for (offset, length) in level_index {
+        ktx2_data.extend_from_slice(&offset.to_le_bytes());
+        ktx2_data.extend_from_slice(&length.to_le_bytes());
+        ktx2_data.extend_from_slice(&length.to_le_bytes());
+    }
+    ktx2_data.extend_from_slice(&dfd);
+    ktx2_data.extend_from_slice(&level_data);
+    Ok(ktx2_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Image;
+    use bevy_asset::RenderAssetUsages;
+    use wgpu_types::{Extent3d, TextureDimension, TextureFormat};
+
+    fn test_image() -> Image {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: 4,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::all(),
+        );
+        image.data.as_mut().unwrap()[4..8].copy_from_slice(&[0, 255, 0, 128]);
+        image
+    }
+
+    #[cfg(feature = "ktx2")]
+    #[test]
+    fn ktx2_round_trip() {
+        let image = test_image();
+        let ktx2_data = super::image_to_ktx2(&image).unwrap();
+        let loaded =
+            crate::ktx2_buffer_to_image(&ktx2_data, crate::CompressedImageFormats::empty(), true)
+                .unwrap();
+        assert_eq!(
+            loaded.texture_descriptor.format,
+            image.texture_descriptor.format
+        );
+        assert_eq!(
+            loaded.texture_descriptor.size,
+            image.texture_descriptor.size
+        );
+        assert_eq!(loaded.data, image.data);
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn png_round_trip() {
+        use super::PngImageSaver;
+        use bevy_asset::saver::{AssetSaver, SavedAsset};
+
+        let image = test_image();
+        let mut png_data = Vec::new();
+        let settings = futures_lite::future::block_on(PngImageSaver.save(
+            &mut png_data,
+            SavedAsset::from_asset(&image),
+            &(),
+        ))
+        .unwrap();
+        assert!(settings.is_srgb);
+
+        let loaded = Image::from_buffer(
+            #[cfg(all(debug_assertions, feature = "dds"))]
+            "test.png".into(),
+            &png_data,
+            crate::ImageType::Format(crate::ImageFormat::Png),
+            crate::CompressedImageFormats::empty(),
+            settings.is_srgb,
+            settings.sampler,
+            settings.asset_usage,
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.texture_descriptor.size,
+            image.texture_descriptor.size
+        );
+        assert_eq!(loaded.data, image.data);
+    }
+}
@@ -24,6 +24,8 @@ mod exr_texture_loader;
 #[cfg(feature = "hdr")]
 mod hdr_texture_loader;
 mod image_loader;
+#[cfg(any(feature = "png", feature = "ktx2"))]
+mod image_saver;
 #[cfg(feature = "ktx2")]
 mod ktx2;
 mod texture_atlas;
@@ -39,6 +41,8 @@ pub use exr_texture_loader::*;
 #[cfg(feature = "hdr")]
 pub use hdr_texture_loader::*;
 pub use image_loader::*;
+#[cfg(any(feature = "png", feature = "ktx2"))]
+pub use image_saver::*;
 #[cfg(feature = "ktx2")]
 pub use ktx2::*;
 pub use texture_atlas::*;
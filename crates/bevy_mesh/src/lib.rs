@@ -6,6 +6,7 @@ extern crate core;
 mod conversions;
 mod index;
 mod mesh;
+mod mesh_asset;
 mod mikktspace;
 pub mod morph;
 pub mod primitives;
@@ -14,6 +15,7 @@ mod vertex;
 use bitflags::bitflags;
 pub use index::*;
 pub use mesh::*;
+pub use mesh_asset::*;
 pub use mikktspace::*;
 pub use primitives::*;
 pub use vertex::*;
@@ -0,0 +1,381 @@
+use super::{Indices, Mesh, MeshVertexAttribute, VertexAttributeValues};
+use alloc::vec::Vec;
+use bevy_asset::{
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    AssetLoader, AsyncWriteExt, LoadContext, RenderAssetUsages,
+};
+use bytemuck::Pod;
+use thiserror::Error;
+use wgpu_types::{PrimitiveTopology, VertexFormat};
+
+/// Unique identifier for the native [`Mesh`] asset format.
+const MESH_ASSET_MAGIC: u64 = 0x4853_454d_5956_4542;
+
+/// The current version of the native [`Mesh`] asset format.
+pub const MESH_ASSET_VERSION: u64 = 1;
+
+/// An [`AssetSaver`] that writes a [`Mesh`] to Bevy's native binary `.mesh` format, which can be loaded back with
+/// [`MeshLoader`].
+///
+/// The vertex attributes, indices, primitive topology and asset usage of the mesh are saved. Meshes with morph
+/// targets are not supported, as the morph targets are stored in a separate [`Image`](bevy_image::Image) asset.
+pub struct MeshSaver;
+
+impl AssetSaver for MeshSaver {
+    type Asset = Mesh;
+    type Settings = ();
+    type OutputLoader = MeshLoader;
+    type Error = MeshSaveOrLoadError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Mesh>,
+        _settings: &(),
+    ) -> Result<(), MeshSaveOrLoadError> {
+        let bytes = write_mesh(&asset)?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+/// An [`AssetLoader`] for meshes in Bevy's native binary `.mesh` format, as written by [`MeshSaver`].
+///
+/// The built-in vertex attributes (such as [`Mesh::ATTRIBUTE_POSITION`]) are always recognized. Custom attributes
+/// must be registered with [`MeshLoader::with_custom_attribute`], otherwise loading fails with
+/// [`MeshSaveOrLoadError::UnknownAttribute`].
+#[derive(Default, Clone)]
+pub struct MeshLoader {
+    custom_attributes: Vec<MeshVertexAttribute>,
+}
+
+impl MeshLoader {
+    /// The vertex attributes that are always recognized by the [`MeshLoader`].
+    pub const BUILTIN_ATTRIBUTES: &'static [MeshVertexAttribute] = &[
+        Mesh::ATTRIBUTE_POSITION,
+        Mesh::ATTRIBUTE_NORMAL,
+        Mesh::ATTRIBUTE_UV_0,
+        Mesh::ATTRIBUTE_UV_1,
+        Mesh::ATTRIBUTE_TANGENT,
+        Mesh::ATTRIBUTE_COLOR,
+        Mesh::ATTRIBUTE_JOINT_WEIGHT,
+        Mesh::ATTRIBUTE_JOINT_INDEX,
+    ];
+
+    /// Registers a custom vertex attribute, so that meshes using it can be loaded.
+    #[must_use]
+    pub fn with_custom_attribute(mut self, attribute: MeshVertexAttribute) -> Self {
+        self.custom_attributes.push(attribute);
+        self
+    }
+
+    fn find_attribute(&self, id: u64) -> Option<MeshVertexAttribute> {
+        Self::BUILTIN_ATTRIBUTES
+            .iter()
+            .chain(&self.custom_attributes)
+            .find(|attribute| attribute.id.0 == id)
+            .cloned()
+    }
+}
+
+impl AssetLoader for MeshLoader {
+    type Asset = Mesh;
+    type Settings = ();
+    type Error = MeshSaveOrLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Mesh, MeshSaveOrLoadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        read_mesh(&bytes, self)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["mesh"]
+    }
+}
+
+/// An error that occurs when saving or loading a [`Mesh`] in the native binary format.
+#[derive(Error, Debug)]
+pub enum MeshSaveOrLoadError {
+    /// The file does not start with the magic number of the native mesh format.
+    #[error("file was not a Mesh asset")]
+    WrongFileType,
+    /// The file was saved with a different version of the native mesh format.
+    #[error("expected asset version {MESH_ASSET_VERSION} but found version {found}")]
+    WrongVersion {
+        /// The version of the file.
+        found: u64,
+    },
+    /// The mesh has morph targets, which cannot be saved.
+    #[error("meshes with morph targets cannot be saved")]
+    MorphTargetsUnsupported,
+    /// The file contains an unknown [`PrimitiveTopology`].
+    #[error("unknown primitive topology {0}")]
+    UnknownPrimitiveTopology(u8),
+    /// The file contains an unknown [`VertexFormat`].
+    #[error("unknown vertex format {0}")]
+    UnknownVertexFormat(u8),
+    /// The file contains an unknown [`Indices`] format.
+    #[error("unknown index format {0}")]
+    UnknownIndexFormat(u8),
+    /// The file contains a vertex attribute which is neither built in nor registered with
+    /// [`MeshLoader::with_custom_attribute`].
+    #[error("unknown vertex attribute with id {0}, custom attributes must be registered on the MeshLoader")]
+    UnknownAttribute(u64),
+    /// The values of a vertex attribute do not have the format of the attribute.
+    #[error("vertex attribute `{name}` expects format {expected:?} but found {found:?}")]
+    InvalidAttributeFormat {
+        /// The name of the attribute.
+        name: &'static str,
+        /// The format of the attribute.
+        expected: VertexFormat,
+        /// The format of the values in the file.
+        found: VertexFormat,
+    },
+    /// Reading or writing the asset failed, or the file is truncated or corrupted.
+    #[error("failed to read or write asset data")]
+    Io(#[from] std::io::Error),
+}
+
+macro_rules! vertex_attribute_values_formats {
+    ($($variant:ident = $tag:literal,)*) => {
+        fn write_values(values: &VertexAttributeValues, bytes: &mut Vec<u8>) {
+            match values {
+                $(VertexAttributeValues::$variant(values) => {
+                    bytes.push($tag);
+                    write_slice(values, bytes);
+                })*
+            }
+        }
+
+        fn read_values(bytes: &mut &[u8]) -> Result<VertexAttributeValues, MeshSaveOrLoadError> {
+            Ok(match read_u8(bytes)? {
+                $($tag => VertexAttributeValues::$variant(read_slice(bytes)?),)*
+                tag => return Err(MeshSaveOrLoadError::UnknownVertexFormat(tag)),
+            })
+        }
+    };
+}
+
+vertex_attribute_values_formats! {
+    Float32 = 0,
+    Sint32 = 1,
+    Uint32 = 2,
+    Float32x2 = 3,
+    Sint32x2 = 4,
+    Uint32x2 = 5,
+    Float32x3 = 6,
+    Sint32x3 = 7,
+    Uint32x3 = 8,
+    Float32x4 = 9,
+    Sint32x4 = 10,
+    Uint32x4 = 11,
+    Sint16x2 = 12,
+    Snorm16x2 = 13,
+    Uint16x2 = 14,
+    Unorm16x2 = 15,
+    Sint16x4 = 16,
+    Snorm16x4 = 17,
+    Uint16x4 = 18,
+    Unorm16x4 = 19,
+    Sint8x2 = 20,
+    Snorm8x2 = 21,
+    Uint8x2 = 22,
+    Unorm8x2 = 23,
+    Sint8x4 = 24,
+    Snorm8x4 = 25,
+    Uint8x4 = 26,
+    Unorm8x4 = 27,
+}
+
+fn write_mesh(mesh: &Mesh) -> Result<Vec<u8>, MeshSaveOrLoadError> {
+    if mesh.has_morph_targets() {
+        return Err(MeshSaveOrLoadError::MorphTargetsUnsupported);
+    }
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&MESH_ASSET_MAGIC.to_le_bytes());
+    bytes.extend_from_slice(&MESH_ASSET_VERSION.to_le_bytes());
+    bytes.push(mesh.primitive_topology() as u8);
+    bytes.push(mesh.asset_usage.bits());
+
+    bytes.extend_from_slice(&(mesh.attributes().count() as u64).to_le_bytes());
+    for (attribute, values) in mesh.attributes() {
+        bytes.extend_from_slice(&attribute.id.0.to_le_bytes());
+        write_values(values, &mut bytes);
+    }
+
+    match mesh.indices() {
+        None => bytes.push(0),
+        Some(Indices::U16(indices)) => {
+            bytes.push(1);
+            write_slice(indices, &mut bytes);
+        }
+        Some(Indices::U32(indices)) => {
+            bytes.push(2);
+            write_slice(indices, &mut bytes);
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn read_mesh(mut bytes: &[u8], loader: &MeshLoader) -> Result<Mesh, MeshSaveOrLoadError> {
+    let bytes = &mut bytes;
+    if read_u64(bytes)? != MESH_ASSET_MAGIC {
+        return Err(MeshSaveOrLoadError::WrongFileType);
+    }
+    let version = read_u64(bytes)?;
+    if version != MESH_ASSET_VERSION {
+        return Err(MeshSaveOrLoadError::WrongVersion { found: version });
+    }
+
+    let primitive_topology = match read_u8(bytes)? {
+        0 => PrimitiveTopology::PointList,
+        1 => PrimitiveTopology::LineList,
+        2 => PrimitiveTopology::LineStrip,
+        3 => PrimitiveTopology::TriangleList,
+        4 => PrimitiveTopology::TriangleStrip,
+        topology => return Err(MeshSaveOrLoadError::UnknownPrimitiveTopology(topology)),
+    };
+    let asset_usage = RenderAssetUsages::from_bits_truncate(read_u8(bytes)?);
+    let mut mesh = Mesh::new(primitive_topology, asset_usage);
+
+    let attribute_count = read_u64(bytes)?;
+    for _ in 0..attribute_count {
+        let id = read_u64(bytes)?;
+        let values = read_values(bytes)?;
+        let attribute = loader
+            .find_attribute(id)
+            .ok_or(MeshSaveOrLoadError::UnknownAttribute(id))?;
+        let format = VertexFormat::from(&values);
+        if format != attribute.format {
+            return Err(MeshSaveOrLoadError::InvalidAttributeFormat {
+                name: attribute.name,
+                expected: attribute.format,
+                found: format,
+            });
+        }
+        mesh.insert_attribute(attribute, values);
+    }
+
+    match read_u8(bytes)? {
+        0 => {}
+        1 => mesh.insert_indices(Indices::U16(read_slice(bytes)?)),
+        2 => mesh.insert_indices(Indices::U32(read_slice(bytes)?)),
+        format => return Err(MeshSaveOrLoadError::UnknownIndexFormat(format)),
+    }
+
+    Ok(mesh)
+}
+
+fn write_slice<T: Pod>(field: &[T], bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&(field.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(bytemuck::cast_slice(field));
+}
+
+fn read_slice<T: Pod>(bytes: &mut &[u8]) -> Result<Vec<T>, std::io::Error> {
+    let len = read_u64(bytes)?;
+    // The length is untrusted, so make sure that the data is there before allocating it.
+    let fits = usize::try_from(len)
+        .ok()
+        .and_then(|len| len.checked_mul(size_of::<T>()))
+        .is_some_and(|size| size <= bytes.len());
+    if !fits {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "slice length exceeds the remaining asset data",
+        ));
+    }
+    let mut data = Vec::new();
+    data.resize(len as usize, T::zeroed());
+    std::io::Read::read_exact(bytes, bytemuck::cast_slice_mut(&mut data))?;
+    Ok(data)
+}
+
+fn read_u64(bytes: &mut &[u8]) -> Result<u64, std::io::Error> {
+    let mut value = [0u8; 8];
+    std::io::Read::read_exact(bytes, &mut value)?;
+    Ok(u64::from_le_bytes(value))
+}
+
+fn read_u8(bytes: &mut &[u8]) -> Result<u8, std::io::Error> {
+    let mut value = [0u8; 1];
+    std::io::Read::read_exact(bytes, &mut value)?;
+    Ok(value[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_mesh, read_slice, write_mesh, MeshLoader, MeshSaveOrLoadError};
+    use crate::{Indices, Mesh, MeshVertexAttribute};
+    use bevy_asset::RenderAssetUsages;
+    use wgpu_types::{PrimitiveTopology, VertexFormat};
+
+    #[test]
+    fn mesh_round_trip() {
+        const ATTRIBUTE_BARYCENTRIC: MeshVertexAttribute =
+            MeshVertexAttribute::new("Barycentric", 2137464976, VertexFormat::Float32x3);
+
+        let mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all())
+            .with_inserted_attribute(
+                Mesh::ATTRIBUTE_POSITION,
+                vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            )
+            .with_inserted_attribute(
+                Mesh::ATTRIBUTE_UV_0,
+                vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            )
+            .with_inserted_attribute(
+                ATTRIBUTE_BARYCENTRIC,
+                vec![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            )
+            .with_inserted_indices(Indices::U16(vec![0, 1, 2]));
+        let bytes = write_mesh(&mesh).unwrap();
+
+        assert!(matches!(
+            read_mesh(&bytes, &MeshLoader::default()),
+            Err(MeshSaveOrLoadError::UnknownAttribute(2137464976))
+        ));
+
+        let loader = MeshLoader::default().with_custom_attribute(ATTRIBUTE_BARYCENTRIC);
+        let loaded = read_mesh(&bytes, &loader).unwrap();
+        assert_eq!(loaded.primitive_topology(), mesh.primitive_topology());
+        assert_eq!(loaded.asset_usage, mesh.asset_usage);
+        for ((loaded_attribute, loaded_values), (attribute, values)) in
+            loaded.attributes().zip(mesh.attributes())
+        {
+            assert_eq!(loaded_attribute.id, attribute.id);
+            assert_eq!(loaded_values.get_bytes(), values.get_bytes());
+        }
+        assert_eq!(loaded.attributes().count(), 3);
+        assert_eq!(
+            loaded.get_index_buffer_bytes(),
+            mesh.get_index_buffer_bytes()
+        );
+    }
+
+    #[test]
+    fn forged_slice_length() {
+        for len in [u64::MAX, u64::MAX / 4, 1 << 40, 2] {
+            let mut bytes = len.to_le_bytes().to_vec();
+            bytes.extend_from_slice(&[0; 4]);
+            let error = read_slice::<[f32; 3]>(&mut bytes.as_slice()).unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        }
+
+        let mesh = Mesh::new(PrimitiveTopology::PointList, RenderAssetUsages::all())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0, 0.0, 0.0]]);
+        let bytes = write_mesh(&mesh).unwrap();
+        assert!(matches!(
+            read_mesh(&bytes[..bytes.len() - 1], &MeshLoader::default()),
+            Err(MeshSaveOrLoadError::Io(_))
+        ));
+    }
+}
@@ -61,6 +61,7 @@ impl Plugin for MeshPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<Mesh>()
             .init_asset::<skinning::SkinnedMeshInverseBindposes>()
+            .init_asset_loader::<MeshLoader>()
             .register_asset_reflect::<Mesh>()
             .register_type::<Mesh3d>()
             .register_type::<skinning::SkinnedMesh>()
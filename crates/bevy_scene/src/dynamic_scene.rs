@@ -178,13 +178,14 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
-    // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into the official Bevy scene format (`.scn` / `.scn.ron`).
     ///
     /// The Bevy scene format is based on [Rusty Object Notation (RON)]. It describes the scene
     /// in a human-friendly format. To deserialize the scene, use the [`SceneLoader`].
+    /// To write the scene through an asset writer, use the [`SceneSaver`].
     ///
     /// [`SceneLoader`]: crate::SceneLoader
+    /// [`SceneSaver`]: crate::SceneSaver
     /// [Rusty Object Notation (RON)]: https://crates.io/crates/ron
     #[cfg(feature = "serialize")]
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
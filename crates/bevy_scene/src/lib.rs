@@ -18,6 +18,7 @@ mod dynamic_scene_builder;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -33,6 +34,7 @@ pub use dynamic_scene_builder::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
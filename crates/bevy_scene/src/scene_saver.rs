@@ -0,0 +1,122 @@
+use crate::ron;
+#[cfg(feature = "serialize")]
+use crate::{DynamicScene, SceneLoader};
+#[cfg(feature = "serialize")]
+use bevy_asset::{
+    io::Writer,
+    saver::{AssetSaver, SavedAsset},
+    AsyncWriteExt,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use thiserror::Error;
+
+/// Asset saver for a Bevy dynamic scene (`.scn` / `.scn.ron`).
+///
+/// The saver writes scenes with [`DynamicScene::serialize`], so they can be loaded back with the [`SceneLoader`].
+#[derive(Debug)]
+pub struct SceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for SceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        SceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`SceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON Error](ron::Error)
+    #[error("Could not serialize the scene to RON: {0}")]
+    RonError(#[from] ron::Error),
+}
+
+#[cfg(feature = "serialize")]
+impl AssetSaver for SceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = SceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &(),
+    ) -> Result<(), Self::Error> {
+        let serialized = asset.serialize(&self.type_registry.read())?;
+        writer.write_all(serialized.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::SceneSaver;
+    use crate::{ron, serde::SceneDeserializer, DynamicScene};
+    use bevy_asset::saver::{AssetSaver, SavedAsset};
+    use bevy_ecs::{
+        entity::hash_map::EntityHashMap,
+        prelude::{Component, ReflectComponent, World},
+        reflect::AppTypeRegistry,
+        world::FromWorld,
+    };
+    use bevy_reflect::Reflect;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[test]
+    fn scene_saver_round_trip() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        world.spawn(Health(3));
+        world.spawn(Health(7));
+        let scene = DynamicScene::from_world(&world);
+
+        let saver = SceneSaver::from_world(&mut world);
+        let mut bytes = Vec::new();
+        futures_lite::future::block_on(saver.save(&mut bytes, SavedAsset::from_asset(&scene), &()))
+            .unwrap();
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes).unwrap();
+        let loaded = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        assert_eq!(2, loaded.entities.len());
+
+        let mut dst_world = World::new();
+        dst_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        loaded
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        let mut health = dst_world
+            .query::<&Health>()
+            .iter(&dst_world)
+            .map(|health| health.0)
+            .collect::<Vec<_>>();
+        health.sort();
+        assert_eq!(vec![3, 7], health);
+    }
+}
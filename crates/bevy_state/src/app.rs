@@ -1,13 +1,23 @@
+use alloc::vec::Vec;
 use bevy_app::{App, MainScheduleOrder, Plugin, PreStartup, PreUpdate, SubApp};
-use bevy_ecs::{event::Events, schedule::IntoSystemConfigs, world::FromWorld};
+use bevy_ecs::{
+    component::Component, event::Events, observer::Trigger, resource::Resource,
+    schedule::IntoSystemConfigs, system::Commands, world::FromWorld,
+};
 use bevy_utils::once;
+use core::marker::PhantomData;
 use log::warn;
 
 use crate::{
     state::{
-        setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
+        clear_state_stack_scoped_entities, register_state_stack, setup_state_transitions_in_world,
+        ComputedStates, FreelyMutableState, NextState, NextStateStack, State, StateStack,
         StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
     },
+    state_machine::{
+        apply_state_machine_transitions, exit_sub_state_machines, update_sub_state_machines,
+        ApplyStateMachineTransition, EnterState, ExitState,
+    },
     state_scoped::clear_state_scoped_entities,
 };
 
@@ -47,6 +57,27 @@ pub trait AppExtStates {
     /// by triggering the [`StateTransition`](struct@StateTransition) schedule manually.
     fn insert_state<S: FreelyMutableState>(&mut self, state: S) -> &mut Self;
 
+    /// Initializes a [`StateStack`] whose bottom state is the standard starting value of `S`.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    ///
+    /// Adds [`State<S>`], [`StateStack<S>`] and [`NextStateStack<S>`] resources, and enables use
+    /// of the [`OnEnter`](crate::state::OnEnter), [`OnExit`](crate::state::OnExit),
+    /// [`OnPause`](crate::state::OnPause), [`OnResume`](crate::state::OnResume) and
+    /// [`OnTransition`](crate::state::OnTransition) schedules. Unlike with
+    /// [`init_state`](Self::init_state), the state is changed through [`NextStateStack<S>`]
+    /// instead of [`NextState<S>`].
+    fn init_state_stack<S: States + FromWorld>(&mut self) -> &mut Self;
+
+    /// Inserts a [`StateStack`] whose bottom state is `state`, and overrides any [`StateStack`]
+    /// previously added of the same type.
+    ///
+    /// States previously added without a stack, such as with [`init_state`](Self::init_state),
+    /// are not overridden: a warning is logged instead.
+    ///
+    /// See [`init_state_stack`](Self::init_state_stack).
+    fn insert_state_stack<S: States>(&mut self, state: S) -> &mut Self;
+
     /// Sets up a type implementing [`ComputedStates`].
     ///
     /// This method is idempotent: it has no effect when called again using the same generic type.
@@ -65,6 +96,37 @@ pub trait AppExtStates {
     /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
 
+    /// Sets up per-entity [`StateMachine<S>`](crate::state_machine::StateMachine)s, whose
+    /// transitions are applied in the [`StateTransition`](struct@StateTransition) schedule.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn add_state_machine<S: States>(&mut self) -> &mut Self;
+
+    /// Sets up per-entity [`StateMachine<S>`](crate::state_machine::StateMachine)s for a type
+    /// implementing [`SubStates`] from a single source state. The sub-state machine of an entity
+    /// is inserted or removed after each transition of the source state machine of the entity,
+    /// according to [`SubStates::should_exist`].
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn add_sub_state_machine<S>(&mut self) -> &mut Self
+    where
+        S: SubStates,
+        S::SourceStates: States;
+
+    /// Inserts the marker component `M` on the entities whose
+    /// [`StateMachine<S>`](crate::state_machine::StateMachine) enters `state`, and removes it when
+    /// they exit it.
+    ///
+    /// This is the per-entity equivalent of the [`in_state`](crate::condition::in_state) run
+    /// condition: the entities in `state` can be queried with the `With<M>` filter.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic types
+    /// and `state`.
+    fn add_state_machine_marker<S: States, M: Component + Default>(
+        &mut self,
+        state: S,
+    ) -> &mut Self;
+
     #[cfg(feature = "bevy_reflect")]
     /// Registers the state type `T` using [`App::register_type`],
     /// and adds [`ReflectState`](crate::reflect::ReflectState) type data to `T` in the type registry.
@@ -85,6 +147,33 @@ pub trait AppExtStates {
         S: FreelyMutableState + FromReflect + GetTypeRegistration + Typed;
 }
 
+/// Marks state machines of type `S` as registered, to keep their registration idempotent.
+#[derive(Resource)]
+struct StateMachineRegistered<S: States>(PhantomData<S>);
+
+impl<S: States> Default for StateMachineRegistered<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// The states for which the marker `M` of state machines of type `S` was registered, to keep
+/// their registration idempotent.
+#[derive(Resource)]
+struct StateMachineMarkerRegistered<S: States, M: Component> {
+    states: Vec<S>,
+    marker: PhantomData<M>,
+}
+
+impl<S: States, M: Component> Default for StateMachineMarkerRegistered<S, M> {
+    fn default() -> Self {
+        Self {
+            states: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
 /// Separate function to only warn once for all state installation methods.
 fn warn_if_no_states_plugin_installed(app: &SubApp) {
     if !app.is_plugin_added::<StatesPlugin>() {
@@ -153,6 +242,59 @@ impl AppExtStates for SubApp {
         self
     }
 
+    fn init_state_stack<S: States + FromWorld>(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<State<S>>() {
+            let state = S::from_world(self.world_mut());
+            self.insert_state_stack(state);
+        } else {
+            let name = core::any::type_name::<S>();
+            warn!("State {} is already initialized.", name);
+        }
+
+        self
+    }
+
+    fn insert_state_stack<S: States>(&mut self, state: S) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self.world().contains_resource::<State<S>>() {
+            self.insert_resource::<State<S>>(State::new(state.clone()))
+                .init_resource::<StateStack<S>>()
+                .init_resource::<NextStateStack<S>>()
+                .add_event::<StateTransitionEvent<S>>();
+            let schedule = self.get_schedule_mut(StateTransition).expect(
+                "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling insert_state_stack?"
+            );
+            register_state_stack::<S>(schedule);
+            self.world_mut().send_event(StateTransitionEvent {
+                exited: None,
+                entered: Some(state),
+            });
+            if S::SCOPED_ENTITIES_ENABLED {
+                self.enable_state_scoped_entities::<S>();
+            }
+        } else if self.world().contains_resource::<StateStack<S>>() {
+            // Overwrite previous state and initial event
+            self.insert_resource::<State<S>>(State::new(state.clone()))
+                .insert_resource(StateStack::<S>::default())
+                .insert_resource(NextStateStack::<S>::default());
+            self.world_mut()
+                .resource_mut::<Events<StateTransitionEvent<S>>>()
+                .clear();
+            self.world_mut().send_event(StateTransitionEvent {
+                exited: None,
+                entered: Some(state),
+            });
+        } else {
+            let name = core::any::type_name::<S>();
+            warn!(
+                "State {} is already initialized without a state stack, so it cannot be used as a state stack.",
+                name
+            );
+        }
+
+        self
+    }
+
     fn add_computed_state<S: ComputedStates>(&mut self) -> &mut Self {
         warn_if_no_states_plugin_installed(self);
         if !self
@@ -224,12 +366,104 @@ impl AppExtStates for SubApp {
         }
         // We work with [`StateTransition`] in set [`StateTransitionSteps::ExitSchedules`] as opposed to [`OnExit`],
         // because [`OnExit`] only runs for one specific variant of the state.
+        if self.world().contains_resource::<StateStack<S>>() {
+            return self.add_systems(
+                StateTransition,
+                clear_state_stack_scoped_entities::<S>.in_set(StateTransitionSteps::ExitSchedules),
+            );
+        }
         self.add_systems(
             StateTransition,
             clear_state_scoped_entities::<S>.in_set(StateTransitionSteps::ExitSchedules),
         )
     }
 
+    fn add_state_machine<S: States>(&mut self) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self
+            .world()
+            .contains_resource::<StateMachineRegistered<S>>()
+        {
+            self.init_resource::<StateMachineRegistered<S>>();
+            self.add_systems(
+                StateTransition,
+                apply_state_machine_transitions::<S>
+                    .in_set(StateTransitionSteps::DependentTransitions)
+                    .in_set(ApplyStateMachineTransition::<S>::default()),
+            );
+        } else {
+            let name = core::any::type_name::<S>();
+            warn!("State machine {} is already initialized.", name);
+        }
+
+        self
+    }
+
+    fn add_sub_state_machine<S>(&mut self) -> &mut Self
+    where
+        S: SubStates,
+        S::SourceStates: States,
+    {
+        if !self
+            .world()
+            .contains_resource::<StateMachineRegistered<S>>()
+        {
+            self.add_state_machine::<S>();
+            self.add_systems(
+                StateTransition,
+                (
+                    exit_sub_state_machines::<S>
+                        .before(ApplyStateMachineTransition::<S::SourceStates>::default()),
+                    update_sub_state_machines::<S>
+                        .after(ApplyStateMachineTransition::<S::SourceStates>::default())
+                        .before(ApplyStateMachineTransition::<S>::default()),
+                )
+                    .in_set(StateTransitionSteps::DependentTransitions),
+            );
+        } else {
+            let name = core::any::type_name::<S>();
+            warn!("Sub state machine {} is already initialized.", name);
+        }
+
+        self
+    }
+
+    fn add_state_machine_marker<S: States, M: Component + Default>(
+        &mut self,
+        state: S,
+    ) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        let mut registered = self
+            .world_mut()
+            .get_resource_or_init::<StateMachineMarkerRegistered<S, M>>();
+        if registered.states.contains(&state) {
+            warn!(
+                "Marker {} of state machine {:?} is already initialized.",
+                core::any::type_name::<M>(),
+                state
+            );
+            return self;
+        }
+        registered.states.push(state.clone());
+
+        let exited = state.clone();
+        self.world_mut().add_observer(
+            move |trigger: Trigger<EnterState<S>>, mut commands: Commands| {
+                if trigger.state == state {
+                    commands.entity(trigger.target()).try_insert(M::default());
+                }
+            },
+        );
+        self.world_mut().add_observer(
+            move |trigger: Trigger<ExitState<S>>, mut commands: Commands| {
+                if trigger.state == exited {
+                    commands.entity(trigger.target()).try_remove::<M>();
+                }
+            },
+        );
+        self
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -266,6 +500,16 @@ impl AppExtStates for App {
         self
     }
 
+    fn init_state_stack<S: States + FromWorld>(&mut self) -> &mut Self {
+        self.main_mut().init_state_stack::<S>();
+        self
+    }
+
+    fn insert_state_stack<S: States>(&mut self, state: S) -> &mut Self {
+        self.main_mut().insert_state_stack::<S>(state);
+        self
+    }
+
     fn add_computed_state<S: ComputedStates>(&mut self) -> &mut Self {
         self.main_mut().add_computed_state::<S>();
         self
@@ -281,6 +525,28 @@ impl AppExtStates for App {
         self
     }
 
+    fn add_state_machine<S: States>(&mut self) -> &mut Self {
+        self.main_mut().add_state_machine::<S>();
+        self
+    }
+
+    fn add_sub_state_machine<S>(&mut self) -> &mut Self
+    where
+        S: SubStates,
+        S::SourceStates: States,
+    {
+        self.main_mut().add_sub_state_machine::<S>();
+        self
+    }
+
+    fn add_state_machine_marker<S: States, M: Component + Default>(
+        &mut self,
+        state: S,
+    ) -> &mut Self {
+        self.main_mut().add_state_machine_marker::<S, M>(state);
+        self
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -317,10 +583,15 @@ impl Plugin for StatesPlugin {
 mod tests {
     use crate::{
         app::StatesPlugin,
-        state::{State, StateTransition, StateTransitionEvent},
+        state::{
+            NextState, NextStateStack, OnEnter, OnExit, OnPause, OnResume, State, StateStack,
+            StateTransition, StateTransitionEvent,
+        },
+        state_scoped::StateScoped,
     };
+    use alloc::{vec, vec::Vec};
     use bevy_app::App;
-    use bevy_ecs::event::Events;
+    use bevy_ecs::{event::Events, resource::Resource, system::ResMut};
     use bevy_state_macros::States;
 
     use super::AppExtStates;
@@ -372,4 +643,124 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[test]
+    fn insert_state_stack_does_not_overwrite_init_state() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+
+        app.init_state::<TestState>();
+        app.insert_state_stack(TestState::B);
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert!(!world.contains_resource::<StateStack<TestState>>());
+        assert!(!world.contains_resource::<NextStateStack<TestState>>());
+
+        // The state can still be changed as usual.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+    }
+
+    #[test]
+    fn insert_state_stack_can_overwrite_insert_state_stack() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+
+        app.insert_state_stack(TestState::B);
+        app.world_mut()
+            .resource_mut::<NextStateStack<TestState>>()
+            .push(TestState::C);
+        app.insert_state_stack(TestState::A);
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert!(world
+            .resource::<StateStack<TestState>>()
+            .covered()
+            .is_empty());
+    }
+
+    #[derive(States, Default, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+    #[states(scoped_entities)]
+    enum Screen {
+        #[default]
+        Gameplay,
+        Pause,
+        Options,
+    }
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<&'static str>);
+
+    fn log(message: &'static str) -> impl Fn(ResMut<Log>) {
+        move |mut log: ResMut<Log>| log.0.push(message)
+    }
+
+    #[test]
+    fn state_stack() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_resource::<Log>()
+            .init_state_stack::<Screen>()
+            .add_systems(OnEnter(Screen::Gameplay), log("enter gameplay"))
+            .add_systems(OnPause(Screen::Gameplay), log("pause gameplay"))
+            .add_systems(OnResume(Screen::Gameplay), log("resume gameplay"))
+            .add_systems(OnExit(Screen::Gameplay), log("exit gameplay"))
+            .add_systems(OnEnter(Screen::Pause), log("enter pause"))
+            .add_systems(OnPause(Screen::Pause), log("pause pause"))
+            .add_systems(OnResume(Screen::Pause), log("resume pause"))
+            .add_systems(OnExit(Screen::Pause), log("exit pause"))
+            .add_systems(OnEnter(Screen::Options), log("enter options"))
+            .add_systems(OnExit(Screen::Options), log("exit options"));
+        let gameplay_entity = app.world_mut().spawn(StateScoped(Screen::Gameplay)).id();
+        let pause_entity = app.world_mut().spawn(StateScoped(Screen::Pause)).id();
+
+        let transition = |app: &mut App, f: fn(&mut NextStateStack<Screen>)| {
+            f(&mut app.world_mut().resource_mut::<NextStateStack<Screen>>());
+            app.world_mut().resource_mut::<Log>().0.clear();
+            app.world_mut().run_schedule(StateTransition);
+            core::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+        };
+
+        assert_eq!(transition(&mut app, |_| {}), vec!["enter gameplay"]);
+        assert_eq!(
+            transition(&mut app, |next| next.push(Screen::Pause)),
+            vec!["pause gameplay", "enter pause"]
+        );
+        assert_eq!(
+            transition(&mut app, |next| next.push(Screen::Options)),
+            vec!["pause pause", "enter options"]
+        );
+        assert_eq!(
+            app.world().resource::<StateStack<Screen>>().covered(),
+            [Screen::Gameplay, Screen::Pause]
+        );
+        assert_eq!(
+            transition(&mut app, NextStateStack::pop),
+            vec!["exit options", "resume pause"]
+        );
+        assert_eq!(
+            transition(&mut app, NextStateStack::pop),
+            vec!["exit pause", "resume gameplay"]
+        );
+        assert_eq!(*app.world().resource::<State<Screen>>(), Screen::Gameplay);
+        assert!(app.world().get_entity(gameplay_entity).is_ok());
+        assert!(app.world().get_entity(pause_entity).is_err());
+
+        // The bottom state can't be popped.
+        assert!(transition(&mut app, NextStateStack::pop).is_empty());
+        assert_eq!(
+            transition(&mut app, |next| next.replace(Screen::Options)),
+            vec!["exit gameplay", "enter options"]
+        );
+        assert!(app.world().get_entity(gameplay_entity).is_err());
+    }
 }
@@ -28,6 +28,9 @@
 //! - A [`StateTransitionEvent<S>`](crate::state::StateTransitionEvent) that gets fired when a given state changes.
 //! - The [`in_state<S>`](crate::condition::in_state) and [`state_changed<S>`](crate::condition::state_changed) run conditions - which are used
 //!   to determine whether a system should run based on the current state.
+//!
+//! States can also be attached to entities with the [`StateMachine<S>`](crate::state_machine::StateMachine) component,
+//! whose transitions trigger observer events targeting the entity instead of running schedules.
 
 #![cfg_attr(
     any(docsrs, docsrs_dep),
@@ -56,6 +59,10 @@ pub mod condition;
 /// Provides definitions for the basic traits required by the state system
 pub mod state;
 
+/// Provides [`StateMachine`](crate::state_machine::StateMachine), a per-entity state machine
+/// component.
+pub mod state_machine;
+
 /// Provides [`StateScoped`](crate::state_scoped::StateScoped) and
 /// [`clear_state_scoped_entities`](crate::state_scoped::clear_state_scoped_entities) for managing lifetime of entities.
 pub mod state_scoped;
@@ -85,10 +92,12 @@ pub mod prelude {
         commands::CommandsStatesExt,
         condition::*,
         state::{
-            last_transition, ComputedStates, EnterSchedules, ExitSchedules, NextState, OnEnter,
-            OnExit, OnTransition, State, StateSet, StateTransition, StateTransitionEvent, States,
-            SubStates, TransitionSchedules,
+            last_transition, ComputedStates, EnterSchedules, ExitSchedules, NextState,
+            NextStateStack, OnEnter, OnExit, OnPause, OnResume, OnTransition, State, StateSet,
+            StateStack, StateTransition, StateTransitionEvent, States, SubStates,
+            TransitionSchedules,
         },
+        state_machine::{EnterState, ExitState, NextEntityState, StateMachine},
         state_scoped::StateScoped,
     };
 }
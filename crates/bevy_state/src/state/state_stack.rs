@@ -0,0 +1,291 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    event::{EventReader, EventWriter},
+    resource::Resource,
+    schedule::{IntoSystemConfigs, IntoSystemSetConfigs, Schedule, ScheduleLabel},
+    system::{Commands, In, IntoSystem, Query, Res, ResMut},
+    world::World,
+};
+use log::warn;
+
+use super::{
+    last_transition, run_transition, ApplyStateTransition, EnterSchedules, ExitSchedules, OnEnter,
+    OnExit, State, StateTransitionEvent, StateTransitionSteps, States, TransitionSchedules,
+};
+use crate::state_scoped::StateScoped;
+
+/// The label of a [`Schedule`] that **only** runs whenever a [`StateStack<S>`] covers the provided
+/// state by pushing another state on top of it.
+///
+/// Unlike [`OnExit`], the covered state stays in the stack, so its
+/// [`StateScoped`] entities are kept.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnPause<S: States>(pub S);
+
+/// The label of a [`Schedule`] that **only** runs whenever a [`StateStack<S>`] uncovers the
+/// provided state by popping the state on top of it.
+///
+/// Unlike [`OnEnter`], this state was already entered when it was paused.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnResume<S: States>(pub S);
+
+/// The states covered by the current [`State<S>`] of a state stack, set up with
+/// [`init_state_stack`](crate::app::AppExtStates::init_state_stack).
+///
+/// The top of the stack is the current [`State<S>`], so that [`in_state`](crate::condition::in_state),
+/// [`OnEnter`], [`OnExit`] and [`OnTransition`](super::OnTransition) work as for other states.
+/// The stack is changed through [`NextStateStack<S>`], and each operation runs the following
+/// schedules:
+///
+/// | Operation | Exited state    | Entered state    |
+/// |-----------|-----------------|------------------|
+/// | Push      | [`OnPause`]     | [`OnEnter`]      |
+/// | Pop       | [`OnExit`]      | [`OnResume`]     |
+/// | Replace   | [`OnExit`]      | [`OnEnter`]      |
+///
+/// [`StateScoped`] entities are only despawned once their state is exited, not while it is
+/// covered.
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum Screen {
+///     #[default]
+///     Gameplay,
+///     Pause,
+///     Options,
+/// }
+///
+/// fn open_options(mut next: ResMut<NextStateStack<Screen>>) {
+///     next.push(Screen::Options);
+/// }
+///
+/// fn close_menu(mut next: ResMut<NextStateStack<Screen>>) {
+///     next.pop();
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct StateStack<S: States> {
+    covered: Vec<S>,
+    last_operation: StateStackOperation,
+}
+
+impl<S: States> Default for StateStack<S> {
+    fn default() -> Self {
+        Self {
+            covered: Vec::new(),
+            last_operation: StateStackOperation::Replace,
+        }
+    }
+}
+
+impl<S: States> StateStack<S> {
+    /// Returns the states covered by the current [`State<S>`], from the bottom of the stack.
+    pub fn covered(&self) -> &[S] {
+        &self.covered
+    }
+
+    /// Returns the number of states covered by the current [`State<S>`].
+    pub fn depth(&self) -> usize {
+        self.covered.len()
+    }
+
+    /// Returns `true` if `state` is covered by the current [`State<S>`].
+    pub fn is_covered(&self, state: &S) -> bool {
+        self.covered.contains(state)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StateStackOperation {
+    Push,
+    Pop,
+    Replace,
+}
+
+/// The next operation on a [`StateStack<S>`], applied during the
+/// [`StateTransition`](super::StateTransition) schedule.
+///
+/// Like [`NextState<S>`](super::NextState), only one operation is applied per transition: the
+/// last one queued wins.
+#[derive(Resource, Debug, Clone, Default)]
+pub enum NextStateStack<S: States> {
+    /// No operation is pending.
+    #[default]
+    Unchanged,
+    /// Covers the current state with this state.
+    Push(S),
+    /// Exits the current state and resumes the state it covers.
+    Pop,
+    /// Exits the current state and enters this state, leaving the covered states unchanged.
+    Replace(S),
+}
+
+impl<S: States> NextStateStack<S> {
+    /// Queues pushing `state` on top of the current state.
+    pub fn push(&mut self, state: S) {
+        *self = Self::Push(state);
+    }
+
+    /// Queues popping the current state.
+    pub fn pop(&mut self) {
+        *self = Self::Pop;
+    }
+
+    /// Queues replacing the current state with `state`.
+    pub fn replace(&mut self, state: S) {
+        *self = Self::Replace(state);
+    }
+
+    /// Discards any pending operation.
+    pub fn reset(&mut self) {
+        *self = Self::Unchanged;
+    }
+}
+
+/// Registers the systems applying the operations of a [`StateStack<S>`] and running its
+/// transition schedules. It is called by `App::init_state_stack`, but can be called manually if
+/// `App` is not used.
+pub fn register_state_stack<S: States>(schedule: &mut Schedule) {
+    schedule.configure_sets((
+        ApplyStateTransition::<S>::default().in_set(StateTransitionSteps::DependentTransitions),
+        ExitSchedules::<S>::default().in_set(StateTransitionSteps::ExitSchedules),
+        TransitionSchedules::<S>::default().in_set(StateTransitionSteps::TransitionSchedules),
+        EnterSchedules::<S>::default().in_set(StateTransitionSteps::EnterSchedules),
+    ));
+
+    schedule
+        .add_systems(apply_state_stack_operation::<S>.in_set(ApplyStateTransition::<S>::default()))
+        .add_systems(
+            last_transition::<S>
+                .pipe(run_state_stack_exit::<S>)
+                .in_set(ExitSchedules::<S>::default()),
+        )
+        .add_systems(
+            last_transition::<S>
+                .pipe(run_transition::<S>)
+                .in_set(TransitionSchedules::<S>::default()),
+        )
+        .add_systems(
+            last_transition::<S>
+                .pipe(run_state_stack_enter::<S>)
+                .in_set(EnterSchedules::<S>::default()),
+        );
+}
+
+fn apply_state_stack_operation<S: States>(
+    mut event: EventWriter<StateTransitionEvent<S>>,
+    mut state: ResMut<State<S>>,
+    mut stack: ResMut<StateStack<S>>,
+    mut next: ResMut<NextStateStack<S>>,
+) {
+    let (entered, operation) = match mem::take(next.bypass_change_detection()) {
+        NextStateStack::Unchanged => return,
+        NextStateStack::Push(entered) => {
+            stack.covered.push(state.get().clone());
+            (entered, StateStackOperation::Push)
+        }
+        NextStateStack::Pop => {
+            let Some(entered) = stack.covered.pop() else {
+                warn!(
+                    "Tried to pop the last state of the state stack {}.",
+                    core::any::type_name::<S>()
+                );
+                return;
+            };
+            (entered, StateStackOperation::Pop)
+        }
+        NextStateStack::Replace(entered) => (entered, StateStackOperation::Replace),
+    };
+    next.set_changed();
+    stack.last_operation = operation;
+
+    let exited = match *state == entered {
+        true => entered.clone(),
+        false => mem::replace(&mut state.0, entered.clone()),
+    };
+    event.send(StateTransitionEvent {
+        exited: Some(exited),
+        entered: Some(entered),
+    });
+}
+
+fn run_state_stack_exit<S: States>(
+    transition: In<Option<StateTransitionEvent<S>>>,
+    world: &mut World,
+) {
+    let Some(StateTransitionEvent {
+        exited: Some(exited),
+        entered,
+    }) = transition.0
+    else {
+        return;
+    };
+    match world.resource::<StateStack<S>>().last_operation {
+        StateStackOperation::Push => {
+            let _ = world.try_run_schedule(OnPause(exited));
+        }
+        StateStackOperation::Replace if entered.as_ref() == Some(&exited) => {}
+        StateStackOperation::Pop | StateStackOperation::Replace => {
+            let _ = world.try_run_schedule(OnExit(exited));
+        }
+    }
+}
+
+fn run_state_stack_enter<S: States>(
+    transition: In<Option<StateTransitionEvent<S>>>,
+    world: &mut World,
+) {
+    let Some(StateTransitionEvent {
+        exited,
+        entered: Some(entered),
+    }) = transition.0
+    else {
+        return;
+    };
+    let Some(exited) = exited else {
+        // The initial state.
+        let _ = world.try_run_schedule(OnEnter(entered));
+        return;
+    };
+    match world.resource::<StateStack<S>>().last_operation {
+        StateStackOperation::Pop => {
+            let _ = world.try_run_schedule(OnResume(entered));
+        }
+        StateStackOperation::Replace if exited == entered => {}
+        StateStackOperation::Push | StateStackOperation::Replace => {
+            let _ = world.try_run_schedule(OnEnter(entered));
+        }
+    }
+}
+
+/// Removes entities marked with [`StateScoped<S>`] when their state is exited from a
+/// [`StateStack<S>`], but not while it is covered.
+pub fn clear_state_stack_scoped_entities<S: States>(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+    state: Res<State<S>>,
+    stack: Res<StateStack<S>>,
+    query: Query<(Entity, &StateScoped<S>)>,
+) {
+    let Some(transition) = transitions.read().last() else {
+        return;
+    };
+    let Some(exited) = &transition.exited else {
+        return;
+    };
+    if *state == *exited || stack.is_covered(exited) {
+        return;
+    }
+    for (entity, binding) in &query {
+        if binding.0 == *exited {
+            commands.entity(entity).despawn();
+        }
+    }
+}
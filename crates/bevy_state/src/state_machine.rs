@@ -0,0 +1,473 @@
+use core::{marker::PhantomData, mem};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::{require, Component},
+    entity::Entity,
+    event::Event,
+    query::{Changed, Or},
+    schedule::SystemSet,
+    system::{Commands, Query},
+    world::Ref,
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+use crate::state::{StateTransitionEvent, States, SubStates};
+
+/// A state machine of type `S` attached to an entity, the per-entity equivalent of
+/// [`State<S>`](crate::state::State).
+///
+/// Transitions are queued with the [`NextEntityState<S>`] component, which is required by this
+/// component, and applied in the [`StateTransition`](crate::state::StateTransition) schedule for
+/// state machines registered with
+/// [`add_state_machine`](crate::app::AppExtStates::add_state_machine).
+///
+/// Instead of running schedules, each transition triggers the following events, targeting the
+/// entity:
+/// - [`ExitState<S>`] with the exited state, unless this is an identity transition,
+/// - [`StateTransitionEvent<S>`], including for identity transitions,
+/// - [`EnterState<S>`] with the entered state, unless this is an identity transition.
+///
+/// The initial state of a state machine is entered in the first
+/// [`StateTransition`](crate::state::StateTransition) after it is inserted.
+///
+/// To query the entities in a given state, register a marker component for it with
+/// [`add_state_machine_marker`](crate::app::AppExtStates::add_state_machine_marker).
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_state::{prelude::*, state_machine::{EnterState, NextEntityState, StateMachine}};
+///
+/// #[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+/// enum Behavior {
+///     #[default]
+///     Idle,
+///     Chasing,
+///     Fleeing,
+/// }
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn spawn_enemy(mut commands: Commands) {
+///     commands
+///         .spawn((
+///             Health(100),
+///             // Enemies never stop fleeing.
+///             StateMachine::new(Behavior::Idle).with_guard(|from, _| *from != Behavior::Fleeing),
+///         ))
+///         .observe(|trigger: Trigger<EnterState<Behavior>>| {
+///             println!("{} is now {:?}", trigger.target(), trigger.state);
+///         });
+/// }
+///
+/// fn flee_when_hurt(mut enemies: Query<(&Health, &mut NextEntityState<Behavior>)>) {
+///     for (health, mut next) in &mut enemies {
+///         if health.0 < 20 {
+///             next.set(Behavior::Fleeing);
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_enemy);
+/// # bevy_ecs::system::assert_is_system(flee_when_hurt);
+/// ```
+#[derive(Component, Clone, Debug)]
+#[require(NextEntityState<S>)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component, Debug))]
+pub struct StateMachine<S: States> {
+    state: S,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    guard: Option<fn(&S, &S) -> bool>,
+    entered: bool,
+}
+
+impl<S: States> StateMachine<S> {
+    /// Creates a state machine starting in `state`.
+    pub fn new(state: S) -> Self {
+        Self {
+            state,
+            guard: None,
+            entered: false,
+        }
+    }
+
+    /// Sets a transition guard, called with the current and the next state for each non-identity
+    /// transition. The transition is discarded if the guard returns `false`.
+    #[must_use]
+    pub fn with_guard(mut self, guard: fn(&S, &S) -> bool) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+
+    /// Returns the current state.
+    pub fn get(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns the state that `next` transitions to, if it is a different state allowed by the
+    /// guard.
+    fn pending_transition<'a>(&self, next: &'a NextEntityState<S>) -> Option<&'a S> {
+        match next {
+            NextEntityState::Pending(entered)
+                if *entered != self.state
+                    && self.guard.is_none_or(|guard| guard(&self.state, entered)) =>
+            {
+                Some(entered)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<S: States + Default> Default for StateMachine<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: States> PartialEq<S> for StateMachine<S> {
+    fn eq(&self, other: &S) -> bool {
+        self.get() == other
+    }
+}
+
+/// The next state of the [`StateMachine<S>`] of an entity, the per-entity equivalent of
+/// [`NextState<S>`](crate::state::NextState).
+///
+/// This component is required by [`StateMachine<S>`], and reset once the transition is applied.
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component, Debug))]
+pub enum NextEntityState<S: States> {
+    /// No state transition is pending.
+    #[default]
+    Unchanged,
+    /// There is a pending transition to this state.
+    Pending(S),
+}
+
+impl<S: States> NextEntityState<S> {
+    /// Queues a transition to `state`.
+    pub fn set(&mut self, state: S) {
+        *self = Self::Pending(state);
+    }
+
+    /// Discards any pending transition.
+    pub fn reset(&mut self) {
+        *self = Self::Unchanged;
+    }
+}
+
+/// Triggered on an entity when its [`StateMachine<S>`] enters a state.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct EnterState<S: States> {
+    /// The entered state.
+    pub state: S,
+}
+
+/// Triggered on an entity when its [`StateMachine<S>`] exits a state.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct ExitState<S: States> {
+    /// The exited state.
+    pub state: S,
+}
+
+/// System set applying the transitions of [`StateMachine<S>`].
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ApplyStateMachineTransition<S: States>(PhantomData<S>);
+
+impl<S: States> Default for ApplyStateMachineTransition<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Enters the initial state of new [`StateMachine<S>`]s, and applies their pending
+/// [`NextEntityState<S>`].
+pub fn apply_state_machine_transitions<S: States>(
+    mut commands: Commands,
+    mut machines: Query<
+        (Entity, &mut StateMachine<S>, &mut NextEntityState<S>),
+        Or<(Changed<StateMachine<S>>, Changed<NextEntityState<S>>)>,
+    >,
+) {
+    for (entity, mut machine, mut next) in &mut machines {
+        if !machine.entered {
+            machine.entered = true;
+            let state = machine.state.clone();
+            commands.trigger_targets(
+                StateTransitionEvent {
+                    exited: None,
+                    entered: Some(state.clone()),
+                },
+                entity,
+            );
+            commands.trigger_targets(EnterState { state }, entity);
+        }
+
+        let next = mem::take(next.bypass_change_detection());
+        let NextEntityState::Pending(entered) = &next else {
+            continue;
+        };
+        if *entered == machine.state {
+            commands.trigger_targets(
+                StateTransitionEvent {
+                    exited: Some(entered.clone()),
+                    entered: Some(entered.clone()),
+                },
+                entity,
+            );
+            continue;
+        }
+        let Some(entered) = machine.pending_transition(&next).cloned() else {
+            continue;
+        };
+
+        let exited = mem::replace(&mut machine.state, entered.clone());
+        commands.trigger_targets(
+            ExitState {
+                state: exited.clone(),
+            },
+            entity,
+        );
+        commands.trigger_targets(
+            StateTransitionEvent {
+                exited: Some(exited),
+                entered: Some(entered.clone()),
+            },
+            entity,
+        );
+        commands.trigger_targets(EnterState { state: entered }, entity);
+    }
+}
+
+/// Exits and removes the [`StateMachine<S>`] of the entities whose source state machine is about
+/// to transition to a state in which `S` should not exist, according to
+/// [`SubStates::should_exist`].
+///
+/// This runs before the source transition is applied, so that [`ExitState<S>`] is triggered
+/// before the [`ExitState`] of the source state.
+pub fn exit_sub_state_machines<S>(
+    mut commands: Commands,
+    machines: Query<
+        (
+            Entity,
+            &StateMachine<S::SourceStates>,
+            &NextEntityState<S::SourceStates>,
+            &StateMachine<S>,
+        ),
+        Changed<NextEntityState<S::SourceStates>>,
+    >,
+) where
+    S: SubStates,
+    S::SourceStates: States,
+{
+    for (entity, source, next, machine) in &machines {
+        let Some(entered) = source.pending_transition(next) else {
+            continue;
+        };
+        if S::should_exist(entered.clone()).is_none() {
+            exit_sub_state_machine(&mut commands, entity, machine);
+        }
+    }
+}
+
+/// Inserts or removes the [`StateMachine<S>`] of the entities whose source state machine was
+/// inserted or transitioned, according to [`SubStates::should_exist`].
+///
+/// Inserted state machines are entered by [`apply_state_machine_transitions`]. State machines
+/// which are removed because of a transition of their source state machine are usually already
+/// exited by [`exit_sub_state_machines`].
+pub fn update_sub_state_machines<S>(
+    mut commands: Commands,
+    machines: Query<(
+        Entity,
+        Ref<StateMachine<S::SourceStates>>,
+        Option<&StateMachine<S>>,
+    )>,
+) where
+    S: SubStates,
+    S::SourceStates: States,
+{
+    for (entity, source, machine) in &machines {
+        if !source.is_changed() {
+            continue;
+        }
+        match (S::should_exist(source.get().clone()), machine) {
+            (Some(initial), None) => {
+                commands.entity(entity).insert(StateMachine::new(initial));
+            }
+            (None, Some(machine)) => exit_sub_state_machine(&mut commands, entity, machine),
+            _ => {}
+        }
+    }
+}
+
+/// Triggers [`ExitState<S>`] and [`StateTransitionEvent<S>`] on `entity` if its state machine
+/// was entered, and removes it.
+fn exit_sub_state_machine<S: States>(
+    commands: &mut Commands,
+    entity: Entity,
+    machine: &StateMachine<S>,
+) {
+    let exited = machine.get().clone();
+    if machine.entered {
+        commands.trigger_targets(
+            ExitState {
+                state: exited.clone(),
+            },
+            entity,
+        );
+        commands.trigger_targets(
+            StateTransitionEvent {
+                exited: Some(exited),
+                entered: None,
+            },
+            entity,
+        );
+    }
+    commands
+        .entity(entity)
+        .remove::<(StateMachine<S>, NextEntityState<S>)>();
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use bevy_app::App;
+    use bevy_ecs::{
+        component::Component,
+        entity::Entity,
+        observer::{Observer, Trigger},
+        query::With,
+        resource::Resource,
+        system::ResMut,
+    };
+
+    use super::{EnterState, ExitState, NextEntityState, StateMachine};
+    use crate::{
+        app::{AppExtStates, StatesPlugin},
+        state::{StateSet, States, SubStates},
+    };
+
+    #[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+    enum Behavior {
+        #[default]
+        Idle,
+        Attacking,
+        Fleeing,
+    }
+
+    #[derive(SubStates, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+    #[source(Behavior = Behavior::Attacking)]
+    enum Attack {
+        #[default]
+        Aiming,
+        Shooting,
+    }
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<&'static str>);
+
+    #[derive(Component, Default)]
+    struct IsAiming;
+
+    #[derive(Component, Default)]
+    struct IsFleeing;
+
+    #[test]
+    fn entity_state_machines() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_resource::<Log>()
+            .add_state_machine::<Behavior>()
+            .add_sub_state_machine::<Attack>()
+            .add_state_machine_marker::<_, IsFleeing>(Behavior::Fleeing)
+            .add_state_machine_marker::<_, IsAiming>(Attack::Aiming);
+        app.add_observer(
+            |trigger: Trigger<EnterState<Behavior>>, mut log: ResMut<Log>| {
+                log.0.push(match trigger.state {
+                    Behavior::Idle => "enter idle",
+                    Behavior::Attacking => "enter attacking",
+                    Behavior::Fleeing => "enter fleeing",
+                });
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<ExitState<Behavior>>, mut log: ResMut<Log>| {
+                log.0.push(match trigger.state {
+                    Behavior::Idle => "exit idle",
+                    Behavior::Attacking => "exit attacking",
+                    Behavior::Fleeing => "exit fleeing",
+                });
+            },
+        )
+        .add_observer(|_: Trigger<EnterState<Attack>>, mut log: ResMut<Log>| {
+            log.0.push("enter attack");
+        })
+        .add_observer(|_: Trigger<ExitState<Attack>>, mut log: ResMut<Log>| {
+            log.0.push("exit attack");
+        });
+
+        let entity = app
+            .world_mut()
+            .spawn(
+                StateMachine::new(Behavior::Idle).with_guard(|from, _| *from != Behavior::Fleeing),
+            )
+            .id();
+        app.update();
+        assert_eq!(app.world().resource::<Log>().0, vec!["enter idle"]);
+
+        let set_next = |app: &mut App, state: Behavior| {
+            app.world_mut()
+                .get_mut::<NextEntityState<Behavior>>(entity)
+                .unwrap()
+                .set(state);
+            app.world_mut().resource_mut::<Log>().0.clear();
+            app.update();
+        };
+
+        set_next(&mut app, Behavior::Attacking);
+        assert_eq!(
+            app.world().resource::<Log>().0,
+            vec!["exit idle", "enter attacking", "enter attack"]
+        );
+        assert!(*app.world().get::<StateMachine<Attack>>(entity).unwrap() == Attack::Aiming);
+        assert!(app.world().entity(entity).contains::<IsAiming>());
+
+        set_next(&mut app, Behavior::Fleeing);
+        assert_eq!(
+            app.world().resource::<Log>().0,
+            vec!["exit attack", "exit attacking", "enter fleeing"]
+        );
+        assert!(app.world().get::<StateMachine<Attack>>(entity).is_none());
+        assert!(!app.world().entity(entity).contains::<IsAiming>());
+        let mut fleeing = app.world_mut().query_filtered::<Entity, With<IsFleeing>>();
+        assert_eq!(fleeing.iter(app.world()).collect::<Vec<_>>(), vec![entity]);
+
+        // The guard prevents leaving `Fleeing`.
+        set_next(&mut app, Behavior::Idle);
+        assert!(app.world().resource::<Log>().0.is_empty());
+        assert!(*app.world().get::<StateMachine<Behavior>>(entity).unwrap() == Behavior::Fleeing);
+    }
+
+    #[test]
+    fn state_machine_marker_is_idempotent() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .add_state_machine::<Behavior>()
+            .add_state_machine_marker::<_, IsFleeing>(Behavior::Fleeing);
+        let mut observers = app.world_mut().query::<&Observer>();
+        let count = observers.iter(app.world()).count();
+
+        app.add_state_machine_marker::<_, IsFleeing>(Behavior::Fleeing);
+        assert_eq!(observers.iter(app.world()).count(), count);
+
+        // The same marker can still be registered for another state.
+        app.add_state_machine_marker::<_, IsFleeing>(Behavior::Attacking);
+        assert_eq!(observers.iter(app.world()).count(), count + 2);
+    }
+}
@@ -0,0 +1,238 @@
+use alloc::{collections::BinaryHeap, vec::Vec};
+use bevy_ecs::{
+    resource::Resource,
+    system::{
+        command::HandleError, entity_command::CommandWithEntity, error_handler, Command, Commands,
+        EntityCommand, EntityCommands,
+    },
+    world::{CommandQueue, World},
+};
+use core::{cmp::Ordering, time::Duration};
+
+use crate::{Time, Virtual};
+
+/// A queue of commands waiting for [`Time<Virtual>`](Virtual) to reach a given elapsed time, ordered by that time.
+///
+/// Due commands are applied in [`First`](bevy_app::First), right after [`Time`] is updated, in the order of their
+/// due time, and then in the order they were queued. Since virtual time is used, pausing or slowing down
+/// [`Time<Virtual>`](Virtual) delays the commands accordingly.
+///
+/// Commands are usually queued through [`DelayedCommandsExt`] and [`DelayedEntityCommandsExt`].
+#[derive(Resource, Default)]
+pub struct DelayedCommandQueue {
+    commands: BinaryHeap<DelayedCommand>,
+    next_index: u64,
+}
+
+impl DelayedCommandQueue {
+    /// Queues `command` to be applied once the elapsed [`Time<Virtual>`](Virtual) reaches `at`.
+    pub fn push(&mut self, at: Duration, command: impl Command) {
+        let mut queue = CommandQueue::default();
+        queue.push(command);
+        self.commands.push(DelayedCommand {
+            at,
+            index: self.next_index,
+            queue,
+        });
+        self.next_index += 1;
+    }
+
+    /// Returns the elapsed [`Time<Virtual>`](Virtual) at which the next command is due, if any.
+    pub fn next_due(&self) -> Option<Duration> {
+        self.commands.peek().map(|command| command.at)
+    }
+
+    /// Returns the number of queued commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns `true` if no command is queued.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Drops all queued commands without applying them.
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+}
+
+struct DelayedCommand {
+    at: Duration,
+    index: u64,
+    queue: CommandQueue,
+}
+
+impl PartialEq for DelayedCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DelayedCommand {}
+
+impl PartialOrd for DelayedCommand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DelayedCommand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the earliest command must compare as the greatest.
+        (other.at, other.index).cmp(&(self.at, self.index))
+    }
+}
+
+/// Applies the commands of the [`DelayedCommandQueue`] that are due.
+///
+/// Commands queued while applying due commands are applied at the earliest during the next update, even if they
+/// are already due.
+pub fn apply_delayed_commands(world: &mut World) {
+    let now = world.resource::<Time<Virtual>>().elapsed();
+    let mut delayed = world.resource_mut::<DelayedCommandQueue>();
+    let mut due = Vec::new();
+    while delayed.next_due().is_some_and(|at| at <= now) {
+        due.extend(delayed.commands.pop());
+    }
+    for mut command in due {
+        command.queue.apply(world);
+    }
+}
+
+/// Extension trait for [`Commands`] to queue commands that are applied after a delay of
+/// [`Time<Virtual>`](Virtual). See [`DelayedCommandQueue`].
+pub trait DelayedCommandsExt {
+    /// Queues `command` to be applied once `delay` of virtual time has passed, starting from when this command is
+    /// applied.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_time::DelayedCommandsExt;
+    /// # use core::time::Duration;
+    /// #[derive(Resource)]
+    /// struct WaveStarted;
+    ///
+    /// fn start_wave_soon(mut commands: Commands) {
+    ///     commands.delayed(Duration::from_secs(2), |world: &mut World| {
+    ///         world.insert_resource(WaveStarted);
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(start_wave_soon);
+    /// ```
+    fn delayed<C: Command<T> + HandleError<T>, T>(&mut self, delay: Duration, command: C);
+
+    /// Queues `command` to be applied once the elapsed [`Time<Virtual>`](Virtual) reaches `at`. If it already did,
+    /// `command` is applied during the next update.
+    fn delayed_until<C: Command<T> + HandleError<T>, T>(&mut self, at: Duration, command: C);
+}
+
+impl DelayedCommandsExt for Commands<'_, '_> {
+    fn delayed<C: Command<T> + HandleError<T>, T>(&mut self, delay: Duration, command: C) {
+        let command = command.handle_error();
+        self.queue(move |world: &mut World| {
+            let at = world.resource::<Time<Virtual>>().elapsed() + delay;
+            world
+                .resource_mut::<DelayedCommandQueue>()
+                .push(at, command);
+        });
+    }
+
+    fn delayed_until<C: Command<T> + HandleError<T>, T>(&mut self, at: Duration, command: C) {
+        let command = command.handle_error();
+        self.queue(move |world: &mut World| {
+            world
+                .resource_mut::<DelayedCommandQueue>()
+                .push(at, command);
+        });
+    }
+}
+
+/// Extension trait for [`EntityCommands`] to queue entity commands that are applied after a delay of
+/// [`Time<Virtual>`](Virtual). See [`DelayedCommandQueue`].
+pub trait DelayedEntityCommandsExt {
+    /// Queues `command` to be applied to the entity once `delay` of virtual time has passed.
+    fn delayed<C: EntityCommand<T> + CommandWithEntity<M>, T, M>(
+        &mut self,
+        delay: Duration,
+        command: C,
+    ) -> &mut Self;
+
+    /// Despawns the entity once `delay` of virtual time has passed.
+    ///
+    /// Unlike [`EntityCommands::despawn`], this does not warn if the entity was already despawned in the meantime.
+    fn despawn_after(&mut self, delay: Duration) -> &mut Self;
+}
+
+impl DelayedEntityCommandsExt for EntityCommands<'_> {
+    fn delayed<C: EntityCommand<T> + CommandWithEntity<M>, T, M>(
+        &mut self,
+        delay: Duration,
+        command: C,
+    ) -> &mut Self {
+        let command = command.with_entity(self.id());
+        self.commands().delayed(delay, command);
+        self
+    }
+
+    fn despawn_after(&mut self, delay: Duration) -> &mut Self {
+        let command = bevy_ecs::system::entity_command::despawn()
+            .with_entity(self.id())
+            .handle_error_with(error_handler::silent());
+        self.commands().delayed(delay, command);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DelayedCommandQueue, DelayedCommandsExt, DelayedEntityCommandsExt};
+    use crate::{Time, TimePlugin, TimeUpdateStrategy, Virtual};
+    use bevy_app::App;
+    use bevy_ecs::{resource::Resource, world::World};
+    use core::time::Duration;
+
+    #[derive(Resource)]
+    struct Done;
+
+    #[test]
+    fn delayed_commands() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(0.5);
+
+        let world = app.world_mut();
+        let entity = world.spawn_empty().id();
+        let mut commands = world.commands();
+        commands
+            .entity(entity)
+            .despawn_after(Duration::from_millis(100));
+        commands.delayed(Duration::from_millis(150), |world: &mut World| {
+            world.insert_resource(Done);
+        });
+        world.flush();
+        assert_eq!(world.resource::<DelayedCommandQueue>().len(), 2);
+
+        // The first update only initializes the clock, then virtual time advances by 50ms per update.
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(entity).is_err());
+        assert!(!app.world().contains_resource::<Done>());
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        assert!(!app.world().contains_resource::<Done>());
+
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        app.update();
+        assert!(app.world().contains_resource::<Done>());
+        assert!(app.world().resource::<DelayedCommandQueue>().is_empty());
+    }
+}
@@ -0,0 +1,286 @@
+use alloc::{boxed::Box, vec::Vec};
+use bevy_app::{App, FixedFirst, FixedLast, Plugin};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ExecutorKind, Schedules},
+};
+use bevy_platform_support::hash::FixedHasher;
+use core::hash::{BuildHasher, Hash, Hasher};
+
+use crate::TimeUpdateStrategy;
+
+/// Puts the [`App`] into a deterministic simulation mode, where two runs fed with the same inputs produce
+/// bit-for-bit identical results. This is the building block for lockstep multiplayer and replays.
+///
+/// This plugin:
+/// - sets [`TimeUpdateStrategy::FixedTimesteps`], so that [`Time<Fixed>`](crate::Fixed) is driven purely by the
+///   frame count: every update runs the same number of fixed timesteps, regardless of wall-clock time.
+/// - switches every schedule of the app to [`ExecutorKind::SingleThreaded`], so systems always run in the same
+///   order. This goes through [`Schedules::forced_executor_kind`], so it also applies to the schedules created
+///   after the app is built, and overrides [`Schedule::set_executor_kind`] once systems are added to a schedule.
+/// - inserts a [`DeterministicRng`] seeded with [`DeterministicPlugin::seed`].
+/// - computes a [`SimulationChecksum`] over the components registered with [`DeterministicPlugin::with_checksum`]
+///   at the end of each fixed timestep, so that diverging simulations can be detected.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{DeterministicPlugin, TimePlugin};
+/// #[derive(Component, Hash)]
+/// struct Health(u32);
+///
+/// App::new().add_plugins((
+///     TimePlugin,
+///     DeterministicPlugin::new(42).with_checksum::<Health>(),
+/// ));
+/// ```
+pub struct DeterministicPlugin {
+    /// The seed of the [`DeterministicRng`] resource.
+    pub seed: u64,
+    /// The number of fixed timesteps run on every update.
+    pub timesteps_per_update: u32,
+    checksums: Vec<Box<dyn Fn(&mut App) + Send + Sync>>,
+}
+
+impl DeterministicPlugin {
+    /// Creates a [`DeterministicPlugin`] that seeds the [`DeterministicRng`] with `seed` and runs a single fixed
+    /// timestep per update.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            timesteps_per_update: 1,
+            checksums: Vec::new(),
+        }
+    }
+
+    /// Sets the number of fixed timesteps run on every update.
+    #[must_use]
+    pub fn with_timesteps_per_update(mut self, timesteps_per_update: u32) -> Self {
+        self.timesteps_per_update = timesteps_per_update;
+        self
+    }
+
+    /// Includes every `C` component in the [`SimulationChecksum`], hashed with its [`Hash`] implementation.
+    #[must_use]
+    pub fn with_checksum<C: Component + Hash>(self) -> Self {
+        self.with_checksum_fn::<C>(|component, mut hasher| component.hash(&mut hasher))
+    }
+
+    /// Includes every `C` component in the [`SimulationChecksum`], hashed with `hash`.
+    ///
+    /// This is useful for components that do not implement [`Hash`], such as the ones containing floats, which
+    /// can be hashed through [`f32::to_bits`].
+    #[must_use]
+    pub fn with_checksum_fn<C: Component>(mut self, hash: fn(&C, &mut dyn Hasher)) -> Self {
+        self.checksums.push(Box::new(move |app| {
+            app.add_systems(
+                FixedLast,
+                (move |query: Query<(Entity, &C)>, mut checksum: ResMut<SimulationChecksum>| {
+                    for (entity, component) in &query {
+                        let mut hasher = FixedHasher.build_hasher();
+                        core::any::type_name::<C>().hash(&mut hasher);
+                        entity.hash(&mut hasher);
+                        hash(component, &mut hasher);
+                        checksum.accumulate(hasher.finish());
+                    }
+                })
+                .in_set(SimulationChecksumSystems),
+            );
+        }));
+        self
+    }
+}
+
+impl Default for DeterministicPlugin {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Plugin for DeterministicPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(
+            self.timesteps_per_update,
+        ))
+        .insert_resource(DeterministicRng::new(self.seed))
+        .init_resource::<SimulationChecksum>()
+        .add_systems(FixedFirst, begin_simulation_checksum);
+
+        for add_checksum in &self.checksums {
+            add_checksum(app);
+        }
+
+        let mut schedules = app.world_mut().get_resource_or_init::<Schedules>();
+        schedules.forced_executor_kind = Some(ExecutorKind::SingleThreaded);
+        for (_, schedule) in schedules.iter_mut() {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        }
+    }
+}
+
+/// The [`SystemSet`] in [`FixedLast`] accumulating the [`SimulationChecksum`] of the current fixed timestep.
+/// Systems that read the checksum of the current timestep should run after this set.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct SimulationChecksumSystems;
+
+/// A checksum of the components registered with [`DeterministicPlugin::with_checksum`], computed every fixed
+/// timestep. Comparing the checksums of two simulations for the same [`tick`](SimulationChecksum::tick) detects
+/// divergence.
+///
+/// The checksum does not depend on the iteration order of entities, but it does depend on their [`Entity`]
+/// identifiers, so entities must be spawned in the same order in both simulations.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationChecksum {
+    tick: u64,
+    value: u64,
+}
+
+impl SimulationChecksum {
+    /// Returns the number of fixed timesteps run since the start of the simulation.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns the checksum of the current fixed timestep.
+    ///
+    /// This is complete after [`SimulationChecksumSystems`] has run.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Mixes `hash` into the checksum of the current fixed timestep. The result does not depend on the order in
+    /// which hashes are accumulated.
+    pub fn accumulate(&mut self, hash: u64) {
+        self.value = self.value.wrapping_add(hash);
+    }
+}
+
+fn begin_simulation_checksum(mut checksum: ResMut<SimulationChecksum>) {
+    checksum.tick += 1;
+    checksum.value = 0;
+}
+
+/// A seedable pseudo-random number generator resource, inserted by the [`DeterministicPlugin`].
+///
+/// Drawing all random numbers of a simulation from this resource makes them reproducible: two simulations started
+/// with the same seed draw the same sequence of numbers, as long as they draw them in the same order.
+///
+/// This implements the `SplitMix64` algorithm, which is fast but not cryptographically secure.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicRng {
+    state: u64,
+}
+
+impl DeterministicRng {
+    /// Creates a new [`DeterministicRng`] from `seed`.
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random [`u64`].
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next random [`u32`].
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns the next random [`f32`] in the range `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Returns the next random [`f64`] in the range `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeterministicPlugin, DeterministicRng, SimulationChecksum};
+    use crate::{Fixed, Time, TimePlugin};
+    use bevy_app::{App, FixedUpdate, Startup, Update};
+    use bevy_ecs::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, Schedules},
+    };
+
+    #[derive(Component, Hash)]
+    struct Counter(u32);
+
+    fn run_simulation(seed: u64) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            DeterministicPlugin::new(seed).with_checksum::<Counter>(),
+        ))
+        .add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Counter(0));
+            commands.spawn(Counter(1));
+        })
+        .add_systems(
+            FixedUpdate,
+            |mut counters: Query<&mut Counter>, mut rng: ResMut<DeterministicRng>| {
+                for mut counter in &mut counters {
+                    counter.0 = counter.0.wrapping_add(rng.next_u32());
+                }
+            },
+        );
+        for _ in 0..5 {
+            app.update();
+        }
+        app
+    }
+
+    #[test]
+    fn deterministic_simulation() {
+        let first = run_simulation(7);
+        let second = run_simulation(7);
+        let other = run_simulation(8);
+
+        // The first update only initializes the clock.
+        let timestep = first.world().resource::<Time<Fixed>>().timestep();
+        assert_eq!(
+            first.world().resource::<Time<Fixed>>().elapsed(),
+            timestep * 4
+        );
+
+        let checksum = *first.world().resource::<SimulationChecksum>();
+        assert_eq!(checksum.tick(), 4);
+        assert_eq!(checksum, *second.world().resource::<SimulationChecksum>());
+        assert_ne!(
+            checksum.value(),
+            other.world().resource::<SimulationChecksum>().value()
+        );
+    }
+
+    #[test]
+    fn late_schedules_are_single_threaded() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Late;
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, DeterministicPlugin::new(0)));
+        app.update();
+
+        let mut late = Schedule::new(Late);
+        late.set_executor_kind(ExecutorKind::Simple);
+        app.add_schedule(late)
+            .add_systems(Late, || {})
+            .add_systems(Update, |world: &mut World| world.run_schedule(Late));
+        app.update();
+
+        let schedules = app.world().resource::<Schedules>();
+        assert_eq!(
+            schedules.get(Late).unwrap().get_executor_kind(),
+            ExecutorKind::SingleThreaded
+        );
+    }
+}
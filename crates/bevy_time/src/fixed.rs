@@ -1,8 +1,18 @@
-use bevy_app::FixedMain;
-use bevy_ecs::world::World;
+use alloc::{vec, vec::Vec};
+use bevy_app::{App, FixedMain};
+use bevy_ecs::{
+    event::{EventRegistry, ShouldUpdateEvents},
+    resource::Resource,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    world::World,
+};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use core::time::Duration;
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
 
 use crate::{time::Time, virt::Virtual};
 
@@ -31,6 +41,8 @@ use crate::{time::Time, virt::Virtual};
 ///
 /// To run a system on a fixed timestep, add it to one of the [`FixedMain`]
 /// schedules, most commonly [`FixedUpdate`](bevy_app::FixedUpdate).
+/// Systems that need a different rate can be added to their own schedule with
+/// [`FixedScheduleAppExt::add_fixed_schedule`], which has its own [`FixedTime`] clock.
 ///
 /// This schedule is run a number of times between
 /// [`PreUpdate`](bevy_app::PreUpdate) and [`Update`](bevy_app::Update)
@@ -232,23 +244,254 @@ impl Default for Fixed {
     }
 }
 
-/// Runs [`FixedMain`] zero or more times based on delta of
-/// [`Time<Virtual>`](Virtual) and [`Time::overstep`].
+/// The fixed timestep clock of an additional fixed timestep schedule `S`, added with
+/// [`FixedScheduleAppExt::add_fixed_schedule`].
+///
+/// This behaves like [`Time<Fixed>`](Fixed), which it dereferences to, but has its own timestep and
+/// accumulator. It is set as the generic [`Time`] resource while `S` runs.
+#[derive(Resource, Debug)]
+pub struct FixedTime<S: ScheduleLabel> {
+    time: Time<Fixed>,
+    marker: PhantomData<S>,
+}
+
+impl<S: ScheduleLabel> FixedTime<S> {
+    /// Creates a new clock for the schedule `S` from a [`Time<Fixed>`](Fixed).
+    pub fn new(time: Time<Fixed>) -> Self {
+        Self {
+            time,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S: ScheduleLabel> Deref for FixedTime<S> {
+    type Target = Time<Fixed>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.time
+    }
+}
+
+impl<S: ScheduleLabel> DerefMut for FixedTime<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.time
+    }
+}
+
+/// Defines the fixed timestep schedules run by
+/// [`RunFixedMainLoopSystem::FixedMainLoop`](bevy_app::RunFixedMainLoopSystem::FixedMainLoop), and their order.
+///
+/// By default, this only contains [`FixedMain`]. Schedules added with
+/// [`FixedScheduleAppExt::add_fixed_schedule`] are run after the existing ones, and can be reordered with
+/// [`FixedScheduleOrder::insert_after`] and [`FixedScheduleOrder::insert_before`].
+///
+/// [`Events`](bevy_ecs::event::Events) are only updated once every fixed timestep schedule has run
+/// since their last update, so that an [`EventReader`](bevy_ecs::event::EventReader) in any of them,
+/// including the ones with a longer timestep than [`FixedMain`], does not miss events.
+#[derive(Resource, Debug, Clone)]
+pub struct FixedScheduleOrder {
+    schedules: Vec<FixedScheduleEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct FixedScheduleEntry {
+    label: InternedScheduleLabel,
+    /// Runs the schedule, returning whether it ran at least once.
+    run: fn(&mut World, InternedScheduleLabel) -> bool,
+    /// Whether the schedule ran since the events were last allowed to update.
+    ran: bool,
+}
+
+impl FixedScheduleEntry {
+    fn new(
+        label: InternedScheduleLabel,
+        run: fn(&mut World, InternedScheduleLabel) -> bool,
+    ) -> Self {
+        Self {
+            label,
+            run,
+            ran: false,
+        }
+    }
+}
+
+impl Default for FixedScheduleOrder {
+    fn default() -> Self {
+        Self {
+            schedules: vec![FixedScheduleEntry::new(FixedMain.intern(), run_fixed_main)],
+        }
+    }
+}
+
+impl FixedScheduleOrder {
+    /// Returns the labels of the fixed timestep schedules, in the order they are run.
+    pub fn labels(&self) -> impl Iterator<Item = InternedScheduleLabel> + '_ {
+        self.schedules.iter().map(|entry| entry.label)
+    }
+
+    /// Moves the fixed timestep `schedule` right after the `after` schedule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either schedule is not a fixed timestep schedule.
+    pub fn insert_after(&mut self, after: impl ScheduleLabel, schedule: impl ScheduleLabel) {
+        let entry = self.remove(&schedule);
+        let index = self.position(&after);
+        self.schedules.insert(index + 1, entry);
+    }
+
+    /// Moves the fixed timestep `schedule` right before the `before` schedule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either schedule is not a fixed timestep schedule.
+    pub fn insert_before(&mut self, before: impl ScheduleLabel, schedule: impl ScheduleLabel) {
+        let entry = self.remove(&schedule);
+        let index = self.position(&before);
+        self.schedules.insert(index, entry);
+    }
+
+    fn position(&self, label: &impl ScheduleLabel) -> usize {
+        self.schedules
+            .iter()
+            .position(|entry| (*entry.label).eq(label))
+            .unwrap_or_else(|| panic!("Expected {label:?} to be a fixed timestep schedule"))
+    }
+
+    fn remove(&mut self, label: &impl ScheduleLabel) -> FixedScheduleEntry {
+        let index = self.position(label);
+        self.schedules.remove(index)
+    }
+
+    fn mark_ran(&mut self, label: InternedScheduleLabel) {
+        if let Some(entry) = self.schedules.iter_mut().find(|entry| entry.label == label) {
+            entry.ran = true;
+        }
+    }
+
+    /// Returns whether every schedule ran since the last call that returned `true`.
+    fn take_all_ran(&mut self) -> bool {
+        let all_ran = self.schedules.iter().all(|entry| entry.ran);
+        if all_ran {
+            for entry in &mut self.schedules {
+                entry.ran = false;
+            }
+        }
+        all_ran
+    }
+}
+
+/// Extension trait for [`App`] to add fixed timestep schedules running at their own rate.
+pub trait FixedScheduleAppExt {
+    /// Adds the schedule `S`, which runs zero or more times per update based on its own
+    /// [`FixedTime<S>`] clock, independently from [`FixedMain`] and [`Time<Fixed>`](Fixed).
+    ///
+    /// The schedule runs after the existing fixed timestep schedules. See [`FixedScheduleOrder`] to change this.
+    /// [`Events`](bevy_ecs::event::Events) are not updated before the schedule had a chance to read them.
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// # use bevy_time::{Fixed, FixedScheduleAppExt, FixedScheduleOrder, Time, TimePlugin};
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct AiUpdate;
+    ///
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct NetworkSend;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(TimePlugin)
+    ///     .add_fixed_schedule(AiUpdate, Time::<Fixed>::from_hz(10.0))
+    ///     .add_fixed_schedule(NetworkSend, Time::<Fixed>::from_hz(30.0));
+    /// app.world_mut()
+    ///     .resource_mut::<FixedScheduleOrder>()
+    ///     .insert_before(AiUpdate, NetworkSend);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` is already a fixed timestep schedule, or if the [`TimePlugin`](crate::TimePlugin) was not
+    /// added.
+    fn add_fixed_schedule<S: ScheduleLabel>(&mut self, schedule: S, time: Time<Fixed>)
+        -> &mut Self;
+}
+
+impl FixedScheduleAppExt for App {
+    fn add_fixed_schedule<S: ScheduleLabel>(
+        &mut self,
+        schedule: S,
+        time: Time<Fixed>,
+    ) -> &mut Self {
+        let label = schedule.intern();
+        let mut order = self.world_mut().resource_mut::<FixedScheduleOrder>();
+        assert!(
+            order.labels().all(|current| current != label),
+            "{label:?} is already a fixed timestep schedule"
+        );
+        order
+            .schedules
+            .push(FixedScheduleEntry::new(label, run_fixed_schedule::<S>));
+        self.insert_resource(FixedTime::<S>::new(time))
+            .init_schedule(label)
+    }
+}
+
+/// Runs the schedules of the [`FixedScheduleOrder`] zero or more times based on delta of
+/// [`Time<Virtual>`](Virtual) and their own [`Time::overstep`].
 /// You can order your systems relative to this by using
 /// [`RunFixedMainLoopSystem`](bevy_app::prelude::RunFixedMainLoopSystem).
+///
+/// Once every schedule has run since the last event update, this signals the
+/// [`EventRegistry`] that the events are ready to be updated.
 pub(super) fn run_fixed_main_schedule(world: &mut World) {
+    let order = world.resource::<FixedScheduleOrder>().clone();
+    for entry in order.schedules {
+        if (entry.run)(world, entry.label) {
+            world
+                .resource_mut::<FixedScheduleOrder>()
+                .mark_ran(entry.label);
+        }
+    }
+
+    // Ensure the events are not dropped until all fixed timestep schedules can observe them
+    if world.resource_mut::<FixedScheduleOrder>().take_all_ran() {
+        if let Some(mut registry) = world.get_resource_mut::<EventRegistry>() {
+            registry.should_update = ShouldUpdateEvents::Ready;
+        }
+    }
+}
+
+/// Runs [`FixedMain`] zero or more times based on delta of
+/// [`Time<Virtual>`](Virtual) and [`Time::overstep`].
+fn run_fixed_main(world: &mut World, label: InternedScheduleLabel) -> bool {
+    run_fixed_schedule_with_clock(world, label, |time: &mut Time<Fixed>| time)
+}
+
+fn run_fixed_schedule<S: ScheduleLabel>(world: &mut World, label: InternedScheduleLabel) -> bool {
+    run_fixed_schedule_with_clock(world, label, |time: &mut FixedTime<S>| &mut time.time)
+}
+
+fn run_fixed_schedule_with_clock<C: Resource>(
+    world: &mut World,
+    label: InternedScheduleLabel,
+    clock: fn(&mut C) -> &mut Time<Fixed>,
+) -> bool {
     let delta = world.resource::<Time<Virtual>>().delta();
-    world.resource_mut::<Time<Fixed>>().accumulate(delta);
+    clock(&mut world.resource_mut::<C>()).accumulate(delta);
 
     // Run the schedule until we run out of accumulated time
-    let _ = world.try_schedule_scope(FixedMain, |world, schedule| {
-        while world.resource_mut::<Time<Fixed>>().expend() {
-            *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+    let mut ran = false;
+    let _ = world.try_schedule_scope(label, |world, schedule| {
+        while clock(&mut world.resource_mut::<C>()).expend() {
+            *world.resource_mut::<Time>() = clock(&mut world.resource_mut::<C>()).as_generic();
             schedule.run(world);
+            ran = true;
         }
     });
 
     *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+    ran
 }
 
 #[cfg(test)]
@@ -358,4 +601,51 @@ mod test {
         assert_eq!(time.elapsed(), Duration::from_secs(6));
         assert_eq!(time.overstep(), Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_fixed_schedules() {
+        use crate::{TimePlugin, TimeUpdateStrategy};
+        use alloc::vec::Vec;
+        use bevy_ecs::{resource::Resource, system::ResMut};
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Slow;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Fast;
+
+        #[derive(Resource, Default)]
+        struct Runs(Vec<&'static str>);
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(Time::<Fixed>::from_seconds(10.0))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )))
+            .init_resource::<Runs>()
+            .add_fixed_schedule(Slow, Time::<Fixed>::from_hz(2.0))
+            .add_fixed_schedule(Fast, Time::<Fixed>::from_hz(4.0))
+            .add_systems(Slow, |mut runs: ResMut<Runs>| runs.0.push("slow"))
+            .add_systems(Fast, |mut runs: ResMut<Runs>| runs.0.push("fast"));
+        app.world_mut()
+            .resource_mut::<FixedScheduleOrder>()
+            .insert_before(Slow, Fast);
+
+        // The first update only initializes the clock.
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Runs>().0, ["fast", "fast", "slow"]);
+        let slow = app.world().resource::<FixedTime<Slow>>();
+        assert_eq!(slow.elapsed(), Duration::from_millis(500));
+        assert_eq!(slow.overstep(), Duration::ZERO);
+        let fast = app.world().resource::<FixedTime<Fast>>();
+        assert_eq!(fast.elapsed(), Duration::from_millis(500));
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().elapsed(),
+            Duration::ZERO
+        );
+    }
 }
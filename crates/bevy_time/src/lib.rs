@@ -14,18 +14,26 @@ extern crate alloc;
 
 /// Common run conditions
 pub mod common_conditions;
+mod delayed_commands;
+mod deterministic;
 mod fixed;
 mod real;
 mod stopwatch;
 mod time;
+mod time_domain;
 mod timer;
+mod timer_component;
 mod virt;
 
+pub use delayed_commands::*;
+pub use deterministic::*;
 pub use fixed::*;
 pub use real::*;
 pub use stopwatch::*;
 pub use time::*;
+pub use time_domain::*;
 pub use timer::*;
+pub use timer_component::*;
 pub use virt::*;
 
 /// The time prelude.
@@ -38,7 +46,7 @@ pub mod prelude {
 
 use bevy_app::{prelude::*, RunFixedMainLoop};
 use bevy_ecs::{
-    event::{event_update_system, signal_event_update_system, EventRegistry, ShouldUpdateEvents},
+    event::{event_update_system, EventRegistry, ShouldUpdateEvents},
     prelude::*,
 };
 use bevy_platform_support::time::Instant;
@@ -65,6 +73,8 @@ impl Plugin for TimePlugin {
             .init_resource::<Time<Real>>()
             .init_resource::<Time<Virtual>>()
             .init_resource::<Time<Fixed>>()
+            .init_resource::<FixedScheduleOrder>()
+            .init_resource::<DelayedCommandQueue>()
             .init_resource::<TimeUpdateStrategy>();
 
         #[cfg(feature = "bevy_reflect")]
@@ -73,7 +83,10 @@ impl Plugin for TimePlugin {
                 .register_type::<Time<Real>>()
                 .register_type::<Time<Virtual>>()
                 .register_type::<Time<Fixed>>()
-                .register_type::<Timer>();
+                .register_type::<Timer>()
+                .register_type::<TimeDomain>()
+                .register_type::<TimerComponent>()
+                .register_type::<StopwatchComponent>();
         }
 
         app.add_systems(
@@ -82,13 +95,22 @@ impl Plugin for TimePlugin {
                 .in_set(TimeSystem)
                 .ambiguous_with(event_update_system),
         )
+        .add_systems(
+            First,
+            (
+                tick_timer_components.in_set(TimerSystem),
+                apply_delayed_commands,
+            )
+                .after(TimeSystem),
+        )
         .add_systems(
             RunFixedMainLoop,
             run_fixed_main_schedule.in_set(RunFixedMainLoopSystem::FixedMainLoop),
-        );
+        )
+        .add_systems(FixedFirst, tick_fixed_timer_components.in_set(TimerSystem));
 
-        // Ensure the events are not dropped until `FixedMain` systems can observe them
-        app.add_systems(FixedPostUpdate, signal_event_update_system);
+        // Ensure the events are not dropped until the fixed timestep systems can observe them,
+        // see `run_fixed_main_schedule`.
         let mut event_registry = app.world_mut().resource_mut::<EventRegistry>();
         // We need to start in a waiting state so that the events are not updated until the first fixed update
         event_registry.should_update = ShouldUpdateEvents::Waiting;
@@ -113,6 +135,14 @@ pub enum TimeUpdateStrategy {
     ManualInstant(Instant),
     /// [`Time`] will be incremented by the specified [`Duration`] each frame.
     ManualDuration(Duration),
+    /// [`Time`] will be incremented by the specified number of [`Time<Fixed>`] timesteps each frame,
+    /// independently of the wall-clock time. The fixed timestep schedules will run exactly that many times per frame.
+    ///
+    /// [`Time<Virtual>`](Virtual) is advanced by exactly that many timesteps, ignoring its
+    /// [`max_delta`](Time::max_delta), relative speed and paused state.
+    ///
+    /// This is used by the [`DeterministicPlugin`].
+    FixedTimesteps(u32),
 }
 
 /// Channel resource used to receive time from the render world.
@@ -140,6 +170,7 @@ pub fn time_system(
     mut real_time: ResMut<Time<Real>>,
     mut virtual_time: ResMut<Time<Virtual>>,
     mut time: ResMut<Time>,
+    fixed_time: Res<Time<Fixed>>,
     update_strategy: Res<TimeUpdateStrategy>,
     #[cfg(feature = "std")] time_recv: Option<Res<TimeReceiver>>,
     #[cfg(feature = "std")] mut has_received_time: Local<bool>,
@@ -169,6 +200,11 @@ pub fn time_system(
         }
         TimeUpdateStrategy::ManualInstant(instant) => real_time.update_with_instant(*instant),
         TimeUpdateStrategy::ManualDuration(duration) => real_time.update_with_duration(*duration),
+        TimeUpdateStrategy::FixedTimesteps(timesteps) => {
+            real_time.update_with_duration(fixed_time.timestep() * *timesteps);
+            advance_virtual_time_exactly(&mut time, &mut virtual_time, real_time.delta());
+            return;
+        }
     }
 
     update_virtual_time(&mut time, &mut virtual_time, &real_time);
@@ -285,6 +321,38 @@ mod tests {
         assert_eq!(counter.0, 2, "Fixed update should have run twice");
     }
 
+    #[test]
+    fn fixed_timesteps_should_run_exactly_n_fixed_updates() {
+        // Enough timesteps per frame to exceed the default maximum delta of virtual time
+        let timesteps = 20;
+        let fixed_update_timestep = Time::<Fixed>::default().timestep();
+        assert!(fixed_update_timestep * timesteps > Time::<Virtual>::default().max_delta());
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_systems(FixedUpdate, count_fixed_updates)
+            .init_resource::<FixedUpdateCounter>()
+            .insert_resource(TimeUpdateStrategy::FixedTimesteps(timesteps));
+
+        // Frame 0 only initializes the clock
+        app.update();
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 0);
+
+        app.update();
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 20);
+
+        // The relative speed and pausing of virtual time do not affect the number of timesteps
+        let mut virtual_time = app.world_mut().resource_mut::<Time<Virtual>>();
+        virtual_time.set_relative_speed(0.5);
+        virtual_time.pause();
+        app.update();
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 40);
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().delta(),
+            fixed_update_timestep * timesteps
+        );
+    }
+
     #[test]
     fn events_get_dropped_regression_test_11528() -> Result<(), impl Error> {
         let (tx1, rx1) = std::sync::mpsc::channel();
@@ -326,6 +394,57 @@ mod tests {
         rx2.try_recv()
     }
 
+    #[test]
+    fn event_update_should_wait_for_slower_fixed_schedules() {
+        use crate::FixedScheduleAppExt;
+        use bevy_ecs::schedule::ScheduleLabel;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct SlowUpdate;
+
+        #[derive(Resource)]
+        struct Sending(bool);
+
+        #[derive(Resource, Default)]
+        struct Received(usize);
+
+        // Send an event every frame, at the rate of `FixedMain`
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_event::<DummyEvent>()
+            .insert_resource(Sending(true))
+            .init_resource::<Received>()
+            .add_fixed_schedule(SlowUpdate, Time::<Fixed>::from_hz(10.0))
+            .add_systems(
+                Update,
+                |sending: Res<Sending>, mut events: EventWriter<DummyEvent>| {
+                    if sending.0 {
+                        events.send(DummyEvent);
+                    }
+                },
+            )
+            .add_systems(
+                SlowUpdate,
+                |mut events: EventReader<DummyEvent>, mut received: ResMut<Received>| {
+                    received.0 += events.read().count();
+                },
+            )
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Time::<Fixed>::default().timestep(),
+            ));
+
+        for _ in 0..20 {
+            app.update();
+        }
+        app.world_mut().resource_mut::<Sending>().0 = false;
+        // Give the slow schedule enough time to run again
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Received>().0, 20);
+    }
+
     #[test]
     fn event_update_should_wait_for_fixed_main() {
         // Set the time step to just over half the fixed update timestep
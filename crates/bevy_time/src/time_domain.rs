@@ -0,0 +1,214 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    system::{Query, Res, SystemParam},
+};
+use core::time::Duration;
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+use crate::time::Time;
+
+/// A local time domain, scaling the time of an entity and of all of its descendants (through [`ChildOf`]).
+///
+/// This allows slowing down or pausing a group of entities, for example the enemies inside of a "bullet time"
+/// bubble, independently from the global [`Time<Virtual>`](crate::Virtual) clock. The speed of nested domains
+/// is multiplied, and pausing a domain pauses all of the domains below it.
+///
+/// A time domain does not have a clock of its own: systems read the scaled delta of an entity through the
+/// [`DomainTime`] system parameter.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{DomainTime, Timer, TimeDomain};
+/// #[derive(Component)]
+/// struct Cooldown(Timer);
+///
+/// #[derive(Component)]
+/// struct Bubble;
+///
+/// fn tick_cooldowns(time: DomainTime, mut cooldowns: Query<(Entity, &mut Cooldown)>) {
+///     for (entity, mut cooldown) in &mut cooldowns {
+///         cooldown.0.tick(time.delta(entity));
+///     }
+/// }
+///
+/// fn enter_bullet_time(mut commands: Commands, bubble: Single<Entity, With<Bubble>>) {
+///     commands
+///         .entity(*bubble)
+///         .insert(TimeDomain::from_relative_speed(0.25));
+/// }
+/// # bevy_ecs::system::assert_is_system(tick_cooldowns);
+/// # bevy_ecs::system::assert_is_system(enter_bullet_time);
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, PartialEq)
+)]
+pub struct TimeDomain {
+    relative_speed: f64,
+    paused: bool,
+}
+
+impl TimeDomain {
+    /// Creates an unpaused time domain running at `ratio` times the speed of its parent domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    pub fn from_relative_speed(ratio: f64) -> Self {
+        let mut domain = Self::default();
+        domain.set_relative_speed(ratio);
+        domain
+    }
+
+    /// Returns the speed of this domain relative to its parent domain.
+    pub fn relative_speed(&self) -> f64 {
+        self.relative_speed
+    }
+
+    /// Sets the speed of this domain relative to its parent domain. A value of `2.0` means that time runs twice
+    /// as fast as in the parent domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    pub fn set_relative_speed(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.relative_speed = ratio;
+    }
+
+    /// Returns the speed of this domain relative to its parent domain, or `0.0` if it is paused.
+    pub fn effective_speed(&self) -> f64 {
+        if self.paused {
+            0.0
+        } else {
+            self.relative_speed
+        }
+    }
+
+    /// Stops time from advancing in this domain and in all of its descendants.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes time in this domain.
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if this domain is paused with [`TimeDomain::pause`].
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+impl Default for TimeDomain {
+    fn default() -> Self {
+        Self {
+            relative_speed: 1.0,
+            paused: false,
+        }
+    }
+}
+
+/// A [`SystemParam`] resolving the time of entities inside of [`TimeDomain`]s.
+///
+/// The delta of an entity is the delta of the generic [`Time`] resource, scaled by the [`TimeDomain`] of the
+/// entity and of each of its ancestors. Entities outside of any time domain use the unscaled delta.
+#[derive(SystemParam)]
+pub struct DomainTime<'w, 's> {
+    time: Res<'w, Time>,
+    domains: Query<'w, 's, (Option<&'static TimeDomain>, Option<&'static ChildOf>)>,
+}
+
+impl<'w, 's> DomainTime<'w, 's> {
+    /// Returns the generic [`Time`] resource the domains are relative to.
+    pub fn time(&self) -> &Time {
+        &self.time
+    }
+
+    /// Returns the effective speed of time for `entity`, relative to the generic [`Time`] resource.
+    pub fn speed(&self, entity: Entity) -> f64 {
+        let mut speed = 1.0;
+        let mut current = Some(entity);
+        while let Some((domain, child_of)) =
+            current.and_then(|entity| self.domains.get(entity).ok())
+        {
+            if let Some(domain) = domain {
+                speed *= domain.effective_speed();
+                if speed == 0.0 {
+                    break;
+                }
+            }
+            current = child_of.map(ChildOf::get);
+        }
+        speed
+    }
+
+    /// Returns `true` if time does not advance for `entity`, because it or one of its ancestors is in a paused
+    /// [`TimeDomain`], or has a relative speed of zero.
+    pub fn is_paused(&self, entity: Entity) -> bool {
+        self.speed(entity) == 0.0
+    }
+
+    /// Returns how much time has advanced for `entity` since the last update, as a [`Duration`].
+    pub fn delta(&self, entity: Entity) -> Duration {
+        let speed = self.speed(entity);
+        if speed == 1.0 {
+            self.time.delta()
+        } else {
+            self.time.delta().mul_f64(speed)
+        }
+    }
+
+    /// Returns how much time has advanced for `entity` since the last update, as [`f32`] seconds.
+    pub fn delta_secs(&self, entity: Entity) -> f32 {
+        self.delta(entity).as_secs_f32()
+    }
+
+    /// Returns how much time has advanced for `entity` since the last update, as [`f64`] seconds.
+    pub fn delta_secs_f64(&self, entity: Entity) -> f64 {
+        self.delta(entity).as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DomainTime, TimeDomain};
+    use crate::Time;
+    use bevy_ecs::{hierarchy::ChildOf, system::SystemState, world::World};
+    use core::time::Duration;
+
+    #[test]
+    fn nested_time_domains() {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+
+        let outside = world.spawn_empty().id();
+        let bubble = world.spawn(TimeDomain::from_relative_speed(0.5)).id();
+        let enemy = world
+            .spawn((ChildOf(bubble), TimeDomain::from_relative_speed(0.5)))
+            .id();
+        let projectile = world.spawn(ChildOf(enemy)).id();
+
+        let mut state = SystemState::<DomainTime>::new(&mut world);
+        let domain_time = state.get(&world);
+        assert_eq!(domain_time.delta(outside), Duration::from_secs(1));
+        assert_eq!(domain_time.delta(bubble), Duration::from_millis(500));
+        assert_eq!(domain_time.delta(projectile), Duration::from_millis(250));
+
+        world.get_mut::<TimeDomain>(bubble).unwrap().pause();
+        let domain_time = state.get(&world);
+        assert!(domain_time.is_paused(projectile));
+        assert_eq!(domain_time.delta(enemy), Duration::ZERO);
+        assert_eq!(domain_time.delta(outside), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,276 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    schedule::SystemSet,
+    system::{Commands, Query, Res},
+};
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+use crate::{DomainTime, Fixed, Real, Stopwatch, Time, Timer};
+
+/// The clock a [`TimerComponent`] or [`StopwatchComponent`] is ticked against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Default))]
+pub enum TimerClock {
+    /// Ticked by [`Time<Virtual>`](crate::Virtual) in [`First`](bevy_app::First), scaled by the
+    /// [`TimeDomain`](crate::TimeDomain) of the entity.
+    #[default]
+    Virtual,
+    /// Ticked by [`Time<Real>`] in [`First`](bevy_app::First).
+    Real,
+    /// Ticked by [`Time<Fixed>`] in [`FixedFirst`](bevy_app::FixedFirst), once per fixed timestep.
+    Fixed,
+}
+
+/// What happens to the entity of a [`TimerComponent`] when its timer finishes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Default))]
+pub enum TimerFinishBehavior {
+    /// Nothing happens, apart from triggering [`TimerFinished`].
+    #[default]
+    Keep,
+    /// The [`TimerComponent`] is removed from the entity.
+    Remove,
+    /// The entity is despawned.
+    Despawn,
+}
+
+/// A [`Timer`] that is ticked automatically by the [`TimePlugin`](crate::TimePlugin), and that triggers
+/// [`TimerFinished`] on its entity every time it finishes.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{TimerComponent, TimerFinished, TimerFinishBehavior, TimerMode};
+/// # let mut world = World::new();
+/// world
+///     .spawn(
+///         TimerComponent::from_seconds(3.0, TimerMode::Once)
+///             .with_finish_behavior(TimerFinishBehavior::Despawn),
+///     )
+///     .observe(|trigger: Trigger<TimerFinished>| {
+///         println!("{} exploded", trigger.target());
+///     });
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, PartialEq)
+)]
+pub struct TimerComponent {
+    /// The ticked timer.
+    pub timer: Timer,
+    /// The clock the timer is ticked against.
+    pub clock: TimerClock,
+    /// What happens when the timer finishes.
+    pub finish_behavior: TimerFinishBehavior,
+}
+
+impl TimerComponent {
+    /// Creates a [`TimerComponent`] ticked against [`TimerClock::Virtual`], which is kept when finished.
+    pub fn new(timer: Timer) -> Self {
+        Self {
+            timer,
+            clock: TimerClock::default(),
+            finish_behavior: TimerFinishBehavior::default(),
+        }
+    }
+
+    /// Creates a [`TimerComponent`] from a [`Timer`] of `duration` seconds.
+    ///
+    /// See [`Timer::from_seconds`].
+    pub fn from_seconds(duration: f32, mode: crate::TimerMode) -> Self {
+        Self::new(Timer::from_seconds(duration, mode))
+    }
+
+    /// Sets the clock the timer is ticked against.
+    #[must_use]
+    pub fn with_clock(mut self, clock: TimerClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets what happens when the timer finishes.
+    #[must_use]
+    pub fn with_finish_behavior(mut self, finish_behavior: TimerFinishBehavior) -> Self {
+        self.finish_behavior = finish_behavior;
+        self
+    }
+}
+
+/// A [`Stopwatch`] that is ticked automatically by the [`TimePlugin`](crate::TimePlugin).
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, PartialEq)
+)]
+pub struct StopwatchComponent {
+    /// The ticked stopwatch.
+    pub stopwatch: Stopwatch,
+    /// The clock the stopwatch is ticked against.
+    pub clock: TimerClock,
+}
+
+impl StopwatchComponent {
+    /// Creates a [`StopwatchComponent`] ticked against `clock`.
+    pub fn new(clock: TimerClock) -> Self {
+        Self {
+            stopwatch: Stopwatch::new(),
+            clock,
+        }
+    }
+}
+
+/// Triggered on the entity of a [`TimerComponent`] when its timer finishes.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerFinished {
+    /// The number of times the timer finished during the last tick. This can be greater than one for
+    /// repeating timers with a short duration.
+    pub times_finished: u32,
+}
+
+/// The [`SystemSet`] ticking [`TimerComponent`]s and [`StopwatchComponent`]s, in [`First`](bevy_app::First)
+/// and [`FixedFirst`](bevy_app::FixedFirst).
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct TimerSystem;
+
+/// Ticks the [`TimerComponent`]s and [`StopwatchComponent`]s using [`TimerClock::Virtual`] and
+/// [`TimerClock::Real`].
+pub fn tick_timer_components(
+    mut commands: Commands,
+    time: DomainTime,
+    real_time: Res<Time<Real>>,
+    mut timers: Query<(Entity, &mut TimerComponent)>,
+    mut stopwatches: Query<(Entity, &mut StopwatchComponent)>,
+) {
+    for (entity, mut timer) in &mut timers {
+        let delta = match timer.clock {
+            TimerClock::Virtual => time.delta(entity),
+            TimerClock::Real => real_time.delta(),
+            TimerClock::Fixed => continue,
+        };
+        tick_timer(&mut commands, entity, &mut timer, delta);
+    }
+    for (entity, mut stopwatch) in &mut stopwatches {
+        let delta = match stopwatch.clock {
+            TimerClock::Virtual => time.delta(entity),
+            TimerClock::Real => real_time.delta(),
+            TimerClock::Fixed => continue,
+        };
+        stopwatch.stopwatch.tick(delta);
+    }
+}
+
+/// Ticks the [`TimerComponent`]s and [`StopwatchComponent`]s using [`TimerClock::Fixed`].
+pub fn tick_fixed_timer_components(
+    mut commands: Commands,
+    fixed_time: Res<Time<Fixed>>,
+    mut timers: Query<(Entity, &mut TimerComponent)>,
+    mut stopwatches: Query<&mut StopwatchComponent>,
+) {
+    let delta = fixed_time.delta();
+    for (entity, mut timer) in &mut timers {
+        if timer.clock == TimerClock::Fixed {
+            tick_timer(&mut commands, entity, &mut timer, delta);
+        }
+    }
+    for mut stopwatch in &mut stopwatches {
+        if stopwatch.clock == TimerClock::Fixed {
+            stopwatch.stopwatch.tick(delta);
+        }
+    }
+}
+
+fn tick_timer(
+    commands: &mut Commands,
+    entity: Entity,
+    timer: &mut TimerComponent,
+    delta: core::time::Duration,
+) {
+    if !timer.timer.tick(delta).just_finished() {
+        return;
+    }
+    commands.trigger_targets(
+        TimerFinished {
+            times_finished: timer.timer.times_finished_this_tick(),
+        },
+        entity,
+    );
+    match timer.finish_behavior {
+        TimerFinishBehavior::Keep => {}
+        TimerFinishBehavior::Remove => {
+            commands.entity(entity).remove::<TimerComponent>();
+        }
+        TimerFinishBehavior::Despawn => {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        StopwatchComponent, TimerClock, TimerComponent, TimerFinishBehavior, TimerFinished,
+    };
+    use crate::{TimePlugin, TimeUpdateStrategy, Timer, TimerMode};
+    use bevy_app::App;
+    use bevy_ecs::{observer::Trigger, resource::Resource, system::ResMut};
+    use core::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Finished(u32);
+
+    #[test]
+    fn timer_components() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .init_resource::<Finished>();
+
+        let repeating = app
+            .world_mut()
+            .spawn(TimerComponent::new(Timer::new(
+                Duration::from_millis(200),
+                TimerMode::Repeating,
+            )))
+            .observe(
+                |_: Trigger<TimerFinished>, mut finished: ResMut<Finished>| {
+                    finished.0 += 1;
+                },
+            )
+            .id();
+        let once = app
+            .world_mut()
+            .spawn(
+                TimerComponent::from_seconds(0.3, TimerMode::Once)
+                    .with_clock(TimerClock::Real)
+                    .with_finish_behavior(TimerFinishBehavior::Despawn),
+            )
+            .id();
+        let stopwatch = app
+            .world_mut()
+            .spawn(StopwatchComponent::new(TimerClock::Virtual))
+            .id();
+
+        // The first update only initializes the clock.
+        for _ in 0..5 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Finished>().0, 2);
+        assert!(app.world().get_entity(once).is_err());
+        let timer = app.world().get::<TimerComponent>(repeating).unwrap();
+        assert_eq!(timer.timer.elapsed(), Duration::ZERO);
+        let stopwatch = app.world().get::<StopwatchComponent>(stopwatch).unwrap();
+        assert_eq!(stopwatch.stopwatch.elapsed(), Duration::from_millis(400));
+    }
+}
@@ -254,6 +254,13 @@ impl Time<Virtual> {
         self.context_mut().effective_speed = effective_speed;
         self.advance_by(delta);
     }
+
+    /// Updates the elapsed duration of `self` by exactly `delta`, ignoring the `max_delta`,
+    /// the relative speed and whether the clock is paused.
+    fn advance_exactly(&mut self, delta: Duration) {
+        self.context_mut().effective_speed = 1.0;
+        self.advance_by(delta);
+    }
 }
 
 impl Default for Virtual {
@@ -276,6 +283,21 @@ pub fn update_virtual_time(current: &mut Time, virt: &mut Time<Virtual>, real: &
     *current = virt.as_generic();
 }
 
+/// Advances [`Time<Virtual>`] and [`Time`] by exactly `delta`, for [`TimeUpdateStrategy::FixedTimesteps`].
+///
+/// Unlike [`update_virtual_time`], this ignores the [`Time::max_delta`], the relative speed and whether
+/// the virtual clock is paused, so that the fixed timestep schedules run exactly the requested number of times.
+///
+/// [`TimeUpdateStrategy::FixedTimesteps`]: crate::TimeUpdateStrategy::FixedTimesteps
+pub(crate) fn advance_virtual_time_exactly(
+    current: &mut Time,
+    virt: &mut Time<Virtual>,
+    delta: Duration,
+) {
+    virt.advance_exactly(delta);
+    *current = virt.as_generic();
+}
+
 #[cfg(test)]
 mod test {
     use super::*;